@@ -0,0 +1,40 @@
+// Times the days that lean hardest on `FastMap`, `FastSet` and `Memoized` against the puzzle
+// inputs in `inputs/`, so a change of hasher can be judged on the real solvers. Days without an
+// input are skipped. `helpers/benches/hashers.rs` has the same comparison key by key.
+use std::fs;
+
+use binaries::registry;
+use criterion::{criterion_group, criterion_main, Criterion};
+use helpers::params::{self, Params};
+use helpers::workspace_root;
+
+const HASH_HEAVY_DAYS: [u32; 5] = [11, 16, 19, 21, 24];
+
+fn days(c: &mut Criterion) {
+    let root = workspace_root();
+    let registry = registry();
+    for day in HASH_HEAVY_DAYS {
+        let solver = registry.get(2024, day).expect("every day is registered");
+        let input_path = solver.input_path(&root);
+        let Ok(contents) = fs::read_to_string(&input_path) else {
+            println!("no input at {:?}, skipping day {day}", input_path);
+            continue;
+        };
+        let params = Params::resolve(solver.params, &input_path, &[])
+            .expect("the input's params have to be valid");
+        for part in 1..=2 {
+            let puzzle = solver.part(part).expect("both parts exist");
+            c.bench_function(&format!("day_{day}_part_{part}"), |b| {
+                b.iter(|| params::with(params.clone(), || puzzle(contents.clone())))
+            });
+        }
+    }
+}
+
+criterion_group! {
+    name = benches;
+    // Whole days are slow enough that criterion's default 100 samples take minutes
+    config = Criterion::default().sample_size(10);
+    targets = days
+}
+criterion_main!(benches);
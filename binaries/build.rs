@@ -0,0 +1,94 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Fingerprints every day module and everything the days share, so the cache can tell which
+// source the running binary was built from rather than what's in the tree right now
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let root = manifest_dir.parent().unwrap();
+    let days_dir = manifest_dir.join("src");
+    let helpers_dir = root.join("helpers").join("src");
+    let lock_path = root.join("Cargo.lock");
+    for path in [&days_dir, &helpers_dir, &lock_path] {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+
+    // Any change to the helpers or a dependency bump could change any answer
+    let mut shared = files_contents(&helpers_dir);
+    shared.extend_from_slice(&fs::read(&lock_path).unwrap_or_default());
+
+    let mut days = Vec::new();
+    for path in files(&days_dir) {
+        let Some((year, day)) = year_and_day(&days_dir, &path) else {
+            continue;
+        };
+        days.push(format!(
+            "({year}, {day}, {:?})",
+            fnv(&fs::read(&path).unwrap())
+        ));
+    }
+
+    let generated = format!(
+        "pub const SOURCE_FINGERPRINTS: helpers::cache::Fingerprints = \
+         helpers::cache::Fingerprints {{ shared: {:?}, days: &[{}] }};\n",
+        fnv(&shared),
+        days.join(", ")
+    );
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("fingerprints.rs");
+    fs::write(out_path, generated).unwrap();
+}
+
+// `year_2024/day_14.rs` is 2024 day 14, same as `scaffold::module_path`
+fn year_and_day(days_dir: &Path, path: &Path) -> Option<(u32, u32)> {
+    let relative = path.strip_prefix(days_dir).ok()?.to_str()?;
+    let (year, day) = relative.split_once('/')?;
+    let year = year.strip_prefix("year_")?.parse().ok()?;
+    let day = day
+        .strip_prefix("day_")?
+        .strip_suffix(".rs")?
+        .parse()
+        .ok()?;
+    Some((year, day))
+}
+
+// Every file under `dir`, in a fixed order so the same tree always hashes the same
+fn files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+// Every file under `dir` with its path, so moving a file changes the fingerprint too
+fn files_contents(dir: &Path) -> Vec<u8> {
+    let mut contents = Vec::new();
+    for path in files(dir) {
+        let relative = path.strip_prefix(dir).unwrap_or(&path);
+        contents.extend_from_slice(relative.to_string_lossy().as_bytes());
+        contents.extend_from_slice(&fs::read(&path).unwrap_or_default());
+    }
+    contents
+}
+
+// The same hash as `runner::hash_input`, which build scripts can't reach
+fn fnv(bytes: &[u8]) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{hash:016x}")
+}
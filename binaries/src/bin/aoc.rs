@@ -0,0 +1,529 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use binaries::{registry, SOURCE_FINGERPRINTS};
+use helpers::batch::{self, BatchRow};
+use helpers::cache::Cache;
+use helpers::dot;
+use helpers::history::{self, HistoryRecord};
+use helpers::isolate::{self, ChildOptions, Limits};
+use helpers::meta::{self, Tag};
+use helpers::params::parse_assignment;
+use helpers::report::{format_timestamp, RunReport};
+use helpers::runner::{run_part_with, stream_part, Solver, Status};
+use helpers::scaffold;
+use helpers::serve::{ServeConfig, Server};
+use helpers::workspace_root;
+
+const USAGE: &str = "usage:
+  aoc run (--all | <year> [<day>...]) [--part <n>] [--input <file>] [--param <key>=<value>]... [--emit-dot] [--stream] [--no-cache]
+      [--isolate] [--time-limit <seconds>] [--memory-limit <MB>] [--json <path>] [--markdown <path>]
+  aoc batch [<year>] <day> <dir> [--param <key>=<value>]... [--jobs <n>] [--no-cache]
+  aoc list [<year>] [--tag <tag>]... [--helper <name>] [--json <path>]
+  aoc history <year> <day> [--threshold <percent>]
+  aoc new <year> <day>
+  aoc serve [--port <n>] [--time-limit <seconds>] [--max-body <bytes>] [--max-connections <n>]";
+
+const DEFAULT_PORT: u16 = 2024;
+
+// Runs more than this much slower than the best for the same input get flagged
+const DEFAULT_THRESHOLD_PERCENT: f64 = 20.0;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("batch") => run_batch(&args[1..]),
+        Some("list") => list(&args[1..]),
+        Some("history") => show_history(&args[1..]),
+        Some("new") => new_day(&args[1..]),
+        Some("serve") => serve(&args[1..]),
+        Some(isolate::CHILD_COMMAND) => {
+            isolate::run_child(&registry(), SOURCE_FINGERPRINTS, &args[1..])
+        }
+        _ => println!("{USAGE}"),
+    }
+}
+
+fn run(args: &[String]) {
+    let root = workspace_root();
+    let mut numbers = Vec::new();
+    let mut all = false;
+    let mut only_part = None;
+    let mut input_name = None;
+    let mut overrides = Vec::new();
+    let mut emit_dot = false;
+    let mut stream = false;
+    let mut use_cache = true;
+    // Either limit implies isolation, there's no enforcing them in this process
+    let mut isolate = false;
+    let mut limits = Limits::default();
+    let mut json_path = root.join("reports").join("report.json");
+    let mut markdown_path = root.join("reports").join("report.md");
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--part" => {
+                only_part = Some(
+                    iter.next()
+                        .and_then(|part| part.parse::<u8>().ok())
+                        .expect(USAGE),
+                )
+            }
+            "--input" => input_name = Some(iter.next().expect(USAGE).clone()),
+            "--param" => match parse_assignment(iter.next().expect(USAGE)) {
+                Ok(assignment) => overrides.push(assignment),
+                Err(err) => {
+                    println!("{err}");
+                    return;
+                }
+            },
+            "--emit-dot" => emit_dot = true,
+            "--stream" => stream = true,
+            "--no-cache" => use_cache = false,
+            "--isolate" => isolate = true,
+            "--time-limit" => {
+                isolate = true;
+                limits.time = Some(parse_time_limit(iter.next().expect(USAGE)))
+            }
+            "--memory-limit" => {
+                isolate = true;
+                let megabytes = iter
+                    .next()
+                    .and_then(|megabytes| megabytes.parse::<u64>().ok())
+                    .expect(USAGE);
+                limits.memory = Some(megabytes * 1024 * 1024);
+            }
+            "--json" => json_path = PathBuf::from(iter.next().expect(USAGE)),
+            "--markdown" => markdown_path = PathBuf::from(iter.next().expect(USAGE)),
+            number => numbers.push(number.parse::<u32>().expect(USAGE)),
+        }
+    }
+
+    let registry = registry();
+    let solvers: Vec<Solver> = match (all, numbers.split_first()) {
+        (true, _) => registry.iter().copied().collect(),
+        (false, Some((year, []))) => registry.year(*year).copied().collect(),
+        (false, Some((year, days))) => days
+            .iter()
+            .filter_map(|day| {
+                let solver = registry.get(*year, *day).copied();
+                if solver.is_none() {
+                    println!("no solver registered for {year} day {day}");
+                }
+                solver
+            })
+            .collect(),
+        (false, None) => {
+            println!("{USAGE}");
+            return;
+        }
+    };
+    if solvers.is_empty() {
+        return;
+    }
+    if input_name.is_some() && solvers.len() != 1 {
+        println!("--input needs exactly one day to run against");
+        return;
+    }
+
+    // Streamed runs only hash the input as they read it and dot graphs are written while
+    // solving, so both always solve
+    let cache = (use_cache && !stream && !emit_dot).then(|| Cache::new(&root, SOURCE_FINGERPRINTS));
+
+    let mut parts = Vec::new();
+    for solver in solvers {
+        let input_path = match &input_name {
+            Some(name) => solver.input_dir(&root).join(name),
+            None => solver.input_path(&root),
+        };
+        // Only days that declare the param know how to draw themselves
+        let mut solver_overrides = overrides.clone();
+        if emit_dot {
+            if solver.params.iter().any(|param| param.name == dot::PARAM) {
+                solver_overrides.push((dot::PARAM.to_string(), "true".to_string()));
+            } else {
+                println!("{} day {} has no dot output", solver.year, solver.day);
+            }
+        }
+        for part in (1..=2).filter(|part| only_part.is_none_or(|only| only == *part)) {
+            let report = if isolate {
+                let options = ChildOptions {
+                    stream,
+                    cache: cache.is_some(),
+                    limits,
+                };
+                isolate::run_isolated(&solver, part, &input_path, &solver_overrides, options)
+            } else if stream {
+                stream_part(&solver, part, &input_path, &solver_overrides)
+            } else {
+                run_part_with(
+                    &solver,
+                    part,
+                    &input_path,
+                    &solver_overrides,
+                    cache.as_ref(),
+                )
+            };
+            let Some(report) = report else {
+                println!("bad puzzle num");
+                return;
+            };
+            println!("{report}");
+            parts.push(report);
+        }
+    }
+
+    let report = RunReport::new(parts);
+    report
+        .write(&json_path, &markdown_path)
+        .expect("should be able to write the report");
+
+    let records = report
+        .parts
+        .iter()
+        .filter_map(|part| HistoryRecord::from_report(report.timestamp, part))
+        .collect::<Vec<_>>();
+    let history_path = history::default_path(&root);
+    if let Err(err) = history::append(&history_path, &records) {
+        println!("failed to record runs in {:?}: {err}", history_path);
+    }
+
+    println!(
+        "{} correct, {} incorrect, {} unverified, {} panicked, {} missing input, {} invalid input, {} invalid params, {} timed out, {} out of memory, {} crashed",
+        report.count(Status::Correct),
+        report.count(Status::Incorrect),
+        report.count(Status::Unverified),
+        report.count(Status::Panicked),
+        report.count(Status::MissingInput),
+        report.count(Status::InvalidInput),
+        report.count(Status::InvalidParams),
+        report.count(Status::TimedOut),
+        report.count(Status::OutOfMemory),
+        report.count(Status::Crashed),
+    );
+    if report.cached() > 0 {
+        println!(
+            "{} answered from the cache, --no-cache to solve them again",
+            report.cached()
+        );
+    }
+    println!("wrote {:?} and {:?}", json_path, markdown_path);
+}
+
+// Seconds as a duration, which has to be a finite number above zero
+fn parse_time_limit(seconds: &str) -> Duration {
+    seconds
+        .parse::<f64>()
+        .ok()
+        .filter(|seconds| *seconds > 0.0)
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .expect(USAGE)
+}
+
+fn run_batch(args: &[String]) {
+    let mut positional = Vec::new();
+    let mut overrides = Vec::new();
+    let mut workers = batch::default_workers();
+    let mut use_cache = true;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--param" => match parse_assignment(iter.next().expect(USAGE)) {
+                Ok(assignment) => overrides.push(assignment),
+                Err(err) => {
+                    println!("{err}");
+                    return;
+                }
+            },
+            "--jobs" => workers = iter.next().and_then(|jobs| jobs.parse().ok()).expect(USAGE),
+            "--no-cache" => use_cache = false,
+            other => positional.push(other),
+        }
+    }
+    let Some((dir, numbers)) = positional.split_last() else {
+        println!("{USAGE}");
+        return;
+    };
+    let numbers = numbers
+        .iter()
+        .map(|number| number.parse::<u32>().expect(USAGE))
+        .collect::<Vec<_>>();
+
+    let registry = registry();
+    // The day alone means the latest year
+    let (year, day) = match numbers[..] {
+        [day] => match registry.years().last() {
+            Some(year) => (*year, day),
+            None => {
+                println!("no solvers registered");
+                return;
+            }
+        },
+        [year, day] => (year, day),
+        _ => {
+            println!("{USAGE}");
+            return;
+        }
+    };
+    let Some(solver) = registry.get(year, day) else {
+        println!("no solver registered for {year} day {day}");
+        return;
+    };
+
+    let root = workspace_root();
+    let cache = use_cache.then(|| Cache::new(&root, SOURCE_FINGERPRINTS));
+    let rows = match batch::run_batch(
+        solver,
+        &PathBuf::from(dir),
+        &overrides,
+        cache.as_ref(),
+        workers,
+    ) {
+        Ok(rows) => rows,
+        Err(err) => {
+            println!("couldn't read inputs from {dir}: {err}");
+            return;
+        }
+    };
+    if rows.is_empty() {
+        println!("no inputs in {dir}");
+        return;
+    }
+
+    print!("{}", batch::format_table(&rows));
+    let parts = rows
+        .into_iter()
+        .flat_map(|row: BatchRow| row.parts)
+        .collect::<Vec<_>>();
+    // The details the table has no room for, like what was expected or why a part failed
+    let failed = parts
+        .iter()
+        .filter(|part| !matches!(part.status, Status::Correct | Status::Unverified))
+        .collect::<Vec<_>>();
+    if !failed.is_empty() {
+        println!();
+        for part in failed {
+            println!("{}: {part}", part.input);
+        }
+    }
+
+    // Parts ran side by side, so the timings aren't fit to go in the history
+    let report = RunReport::new(parts);
+    println!(
+        "{} correct, {} incorrect, {} unverified, {} panicked, {} invalid input, {} invalid params",
+        report.count(Status::Correct),
+        report.count(Status::Incorrect),
+        report.count(Status::Unverified),
+        report.count(Status::Panicked),
+        report.count(Status::InvalidInput),
+        report.count(Status::InvalidParams),
+    );
+    if report.cached() > 0 {
+        println!(
+            "{} answered from the cache, --no-cache to solve them again",
+            report.cached()
+        );
+    }
+}
+
+fn list(args: &[String]) {
+    let mut year = None;
+    let mut tags = Vec::new();
+    let mut helper = None;
+    let mut json_path = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--tag" => match iter.next().expect(USAGE).parse::<Tag>() {
+                Ok(tag) => tags.push(tag),
+                Err(err) => {
+                    println!("{err}");
+                    return;
+                }
+            },
+            "--helper" => helper = Some(iter.next().expect(USAGE).clone()),
+            "--json" => json_path = Some(PathBuf::from(iter.next().expect(USAGE))),
+            number => year = Some(number.parse::<u32>().expect(USAGE)),
+        }
+    }
+
+    // Every tag given has to match, so `--tag grid --tag graph` is grid searches
+    let infos = registry()
+        .infos()
+        .into_iter()
+        .filter(|info| year.is_none_or(|year| info.year == year))
+        .filter(|info| tags.iter().all(|tag| info.has_tag(*tag)))
+        .filter(|info| {
+            helper
+                .as_ref()
+                .is_none_or(|helper| info.helpers.contains(helper))
+        })
+        .collect::<Vec<_>>();
+
+    for info in &infos {
+        println!("{info}");
+    }
+    if infos.is_empty() {
+        println!("no days match");
+    }
+    if let Some(path) = json_path {
+        match fs::write(&path, meta::to_json(&infos)) {
+            Ok(()) => println!("wrote {:?}", path),
+            Err(err) => println!("couldn't write {:?}: {err}", path),
+        }
+    }
+}
+
+fn show_history(args: &[String]) {
+    let mut numbers = Vec::new();
+    let mut threshold_percent = DEFAULT_THRESHOLD_PERCENT;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--threshold" => {
+                threshold_percent = iter
+                    .next()
+                    .and_then(|percent| percent.trim_end_matches('%').parse().ok())
+                    .expect(USAGE)
+            }
+            number => numbers.push(number.parse::<u32>().expect(USAGE)),
+        }
+    }
+    let [year, day] = numbers[..] else {
+        println!("{USAGE}");
+        return;
+    };
+
+    let history_path = history::default_path(&workspace_root());
+    let records = history::load(&history_path)
+        .expect("should be able to read the history file")
+        .into_iter()
+        .filter(|record| record.year == year && record.day == day)
+        .collect::<Vec<_>>();
+    if records.is_empty() {
+        println!(
+            "no recorded runs for {year} day {day} in {:?}",
+            history_path
+        );
+        return;
+    }
+
+    let mut entries = history::compare_to_best(records, threshold_percent / 100.0);
+    entries.sort_by(|a, b| {
+        let group = |record: &HistoryRecord| {
+            (
+                record.part,
+                record.variant.clone(),
+                record.input_hash.clone(),
+                record.params.clone(),
+            )
+        };
+        group(&a.record)
+            .cmp(&group(&b.record))
+            .then(a.record.timestamp.cmp(&b.record.timestamp))
+    });
+
+    let mut current_group = None;
+    for entry in entries.iter() {
+        let record = &entry.record;
+        let group = (
+            record.part,
+            &record.variant,
+            &record.input_hash,
+            &record.params,
+        );
+        if current_group != Some(group) {
+            let params = record
+                .params
+                .iter()
+                .map(|(name, value)| format!(" {name}={value}"))
+                .collect::<String>();
+            println!(
+                "{year} day {day} part {} ({}) input {}{params} - best {:.3} ms",
+                record.part,
+                record.variant,
+                &record.input_hash[..8],
+                entry.best_solve_ms
+            );
+            current_group = Some(group);
+        }
+        let flag = if entry.flagged {
+            format!("  <-- {:.0}% slower than best", entry.slowdown * 100.0)
+        } else {
+            String::new()
+        };
+        println!(
+            "  {}  {:>12.3} ms  {} [{}]{flag}",
+            format_timestamp(record.timestamp),
+            record.solve_ms,
+            record.answer,
+            record.status
+        );
+    }
+
+    let flagged = entries.iter().filter(|entry| entry.flagged).count();
+    if flagged > 0 {
+        println!(
+            "{flagged} run(s) more than {threshold_percent}% slower than the best for their input"
+        );
+    }
+}
+
+fn new_day(args: &[String]) {
+    let numbers = args
+        .iter()
+        .map(|arg| arg.parse::<u32>().expect(USAGE))
+        .collect::<Vec<_>>();
+    let [year, day] = numbers[..] else {
+        println!("{USAGE}");
+        return;
+    };
+
+    match scaffold::new_day(&workspace_root(), year, day) {
+        Ok(touched) => {
+            println!("set up {year} day {day}:");
+            for path in touched {
+                println!("  {:?}", path);
+            }
+        }
+        Err(err) => println!("couldn't set up {year} day {day}: {err}"),
+    }
+}
+
+fn serve(args: &[String]) {
+    let mut port = DEFAULT_PORT;
+    let mut config = ServeConfig::default();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = iter.next().expect(USAGE);
+        match arg.as_str() {
+            "--port" => port = value.parse().expect(USAGE),
+            "--time-limit" => config.time_limit = parse_time_limit(value),
+            "--max-body" => config.max_body_bytes = value.parse().expect(USAGE),
+            "--max-connections" => config.max_connections = value.parse().expect(USAGE),
+            _ => {
+                println!("{USAGE}");
+                return;
+            }
+        }
+    }
+
+    let server = Server::bind(("127.0.0.1", port), registry(), config)
+        .expect("should be able to bind the port");
+    println!(
+        "serving on http://{}",
+        server.local_addr().expect("listener has an address")
+    );
+    if let Err(err) = server.run() {
+        println!("stopped serving: {err}");
+    }
+}
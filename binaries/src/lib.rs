@@ -0,0 +1,86 @@
+use helpers::runner::Registry;
+
+pub mod year_2024;
+
+// `SOURCE_FINGERPRINTS`, what this build's answers are cached under. Written by `build.rs`
+include!(concat!(env!("OUT_DIR"), "/fingerprints.rs"));
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::default();
+    year_2024::register(&mut registry);
+    registry
+}
+
+#[cfg(test)]
+mod test_registry {
+    use std::collections::BTreeSet;
+    use std::fs;
+
+    use helpers::scaffold::module_path;
+    use helpers::workspace_root;
+
+    use crate::registry;
+
+    // What `helpers` exports from its root, named by the module it lives in
+    const ROOT_ITEMS: &[(&str, &str)] = &[
+        ("read_grid", "read_grid"),
+        ("BitGrid", "bit_grid"),
+        ("BitVec", "bit_vec"),
+        ("Rational", "rational"),
+        ("Trie", "trie"),
+        ("AhoCorasick", "trie"),
+        ("HeapNode", "heap"),
+        ("IndexedBinaryHeap", "heap"),
+        ("UpdateKeyResult", "heap"),
+    ];
+
+    // Every day uses these, listing them wouldn't say anything
+    const IGNORED: &[&str] = &["Answer", "Puzzle", "meta"];
+
+    fn used_helpers(source: &str) -> BTreeSet<String> {
+        source
+            .split("use helpers::")
+            .skip(1)
+            .map(|rest| rest.split_once(';').expect("use ends with ;").0)
+            .flat_map(|path| match path.strip_prefix('{') {
+                Some(items) => items
+                    .trim_end_matches('}')
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .collect::<Vec<_>>(),
+                None => vec![path],
+            })
+            .map(|item| item.split("::").next().expect("split is never empty"))
+            .filter(|name| !IGNORED.contains(name))
+            .map(
+                |name| match ROOT_ITEMS.iter().find(|(item, _)| *item == name) {
+                    Some((_, module)) => module.to_string(),
+                    None if name.starts_with(char::is_lowercase) => name.to_string(),
+                    None => panic!("no idea which module {name} is from, add it to ROOT_ITEMS"),
+                },
+            )
+            .collect()
+    }
+
+    #[test]
+    fn test_declared_helpers_match_source() {
+        let root = workspace_root();
+        for solver in registry().iter() {
+            let source = fs::read_to_string(module_path(&root, solver.year, solver.day))
+                .expect("every registered day has a module");
+            let declared = solver
+                .helpers
+                .iter()
+                .map(|name| name.to_string())
+                .collect::<BTreeSet<_>>();
+            assert_eq!(
+                used_helpers(&source),
+                declared,
+                "{} day {} HELPERS don't match its imports",
+                solver.year,
+                solver.day
+            );
+        }
+    }
+}
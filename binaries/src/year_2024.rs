@@ -0,0 +1,55 @@
+use helpers::runner::Registry;
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_2;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+pub fn register(registry: &mut Registry) {
+    registry.add::<day_1::Day1>();
+    registry.add::<day_2::Day2>();
+    registry.add::<day_3::Day3>();
+    registry.add::<day_4::Day4>();
+    registry.add::<day_5::Day5>();
+    registry.add::<day_6::Day6>();
+    registry.add::<day_7::Day7>();
+    registry.add::<day_8::Day8>();
+    registry.add::<day_9::Day9>();
+    registry.add::<day_10::Day10>();
+    registry.add::<day_11::Day11>();
+    registry.add::<day_12::Day12>();
+    registry.add::<day_13::Day13>();
+    registry.add::<day_14::Day14>();
+    registry.add::<day_15::Day15>();
+    registry.add::<day_16::Day16>();
+    registry.add::<day_17::Day17>();
+    registry.add::<day_18::Day18>();
+    registry.add::<day_19::Day19>();
+    registry.add::<day_20::Day20>();
+    registry.add::<day_21::Day21>();
+    registry.add::<day_22::Day22>();
+    registry.add::<day_23::Day23>();
+    registry.add::<day_24::Day24>();
+    registry.add::<day_25::Day25>();
+}
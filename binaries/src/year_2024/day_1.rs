@@ -1,19 +1,32 @@
-use std::{collections::HashMap, env, fs};
+use std::{collections::HashMap, env, fs, io::BufRead};
 
-use helpers::Puzzle;
+use helpers::meta::Tag;
+use helpers::{stream, Answer, Puzzle};
 
-fn main() {
-    Day1::run();
-}
-
-struct Day1;
+pub struct Day1;
 
 impl Puzzle for Day1 {
-    fn puzzle_1(contents: String) {
-        let mut list_1 = Vec::with_capacity(contents.len());
-        let mut list_2 = Vec::with_capacity(contents.len());
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+    const TAGS: &'static [Tag] = &[Tag::Parsing];
+    const HELPERS: &'static [&'static str] = &["stream"];
+    const NOTES: &'static str = "Sorts both lists for part 1 and counts the right list in a map \
+        for part 2, O(n log n)";
+
+    fn puzzle_1(contents: String) -> Answer {
+        Self::stream_1(&mut contents.as_bytes())
+    }
+
+    fn puzzle_2(contents: String) -> Answer {
+        Self::stream_2(&mut contents.as_bytes())
+    }
+
+    fn stream_1(input: &mut dyn BufRead) -> Answer {
+        let mut list_1 = Vec::new();
+        let mut list_2 = Vec::new();
 
-        for line in contents.lines() {
+        for line in stream::lines(input) {
             let mut split_line = line.split_whitespace();
             list_1.push(
                 split_line
@@ -39,14 +52,14 @@ impl Puzzle for Day1 {
             .zip(list_2.iter())
             .fold(0, |acc, pair| acc + (pair.0 - pair.1).abs());
 
-        println!("The diff in location ids is: {val}");
+        val.into()
     }
 
-    fn puzzle_2(contents: String) {
-        let mut list_1 = Vec::with_capacity(contents.len());
+    fn stream_2(input: &mut dyn BufRead) -> Answer {
+        let mut list_1 = Vec::new();
         let mut map = HashMap::new();
 
-        for line in contents.lines() {
+        for line in stream::lines(input) {
             let mut split_line = line.split_whitespace();
             list_1.push(
                 split_line
@@ -71,6 +84,6 @@ impl Puzzle for Day1 {
             acc + (val * count)
         });
 
-        println!("Similarity score is: {result}");
+        result.into()
     }
 }
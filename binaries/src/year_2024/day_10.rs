@@ -1,12 +1,9 @@
 use std::{collections::HashSet, iter, thread::current};
 
-use helpers::{read_grid, Puzzle};
+use helpers::meta::Tag;
+use helpers::{read_grid, Answer, Puzzle};
 
-fn main() {
-    Day10::run();
-}
-
-struct Day10;
+pub struct Day10;
 
 fn hike<T>(heights: &Vec<Vec<u32>>, row: usize, col: usize, end_fn: &mut T)
 where
@@ -58,7 +55,15 @@ fn convert_grid(char_grid: Vec<Vec<char>>) -> Vec<Vec<u32>> {
 }
 
 impl Puzzle for Day10 {
-    fn puzzle_1(contents: String) {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Hoof It";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Graph];
+    const HELPERS: &'static [&'static str] = &["read_grid"];
+    const NOTES: &'static str = "DFS up from every trailhead, distinct peaks for part 1 and every \
+        path for part 2";
+
+    fn puzzle_1(contents: String) -> Answer {
         let grid = convert_grid(read_grid(contents));
 
         let mut search_fn = |heights: &Vec<Vec<u32>>, coords: (usize, usize)| {
@@ -71,10 +76,10 @@ impl Puzzle for Day10 {
         };
         let sum = search_grid(&grid, &mut search_fn);
 
-        println!("trailhead score is: {sum}");
+        sum.into()
     }
 
-    fn puzzle_2(contents: String) {
+    fn puzzle_2(contents: String) -> Answer {
         let grid = convert_grid(read_grid(contents));
 
         let mut search_fn = |heights: &Vec<Vec<u32>>, coords: (usize, usize)| {
@@ -87,6 +92,6 @@ impl Puzzle for Day10 {
         };
         let sum = search_grid(&grid, &mut search_fn);
 
-        println!("trailhead rank is: {sum}");
+        sum.into()
     }
 }
@@ -0,0 +1,57 @@
+use helpers::memo::Memoized;
+use helpers::meta::Tag;
+use helpers::{Answer, Puzzle};
+
+pub struct Day11;
+
+// How many stones there are after blinking `num_blinks` times
+fn count_stones(stones: &[u64], num_blinks: usize) -> usize {
+    let mut count = Memoized::new(|count, &(stone, blinks): &(u64, usize)| {
+        if blinks == 0 {
+            return 1;
+        }
+        if stone == 0 {
+            return count.call(&(1, blinks - 1));
+        }
+        let digits = stone.ilog10() + 1;
+        if digits % 2 == 0 {
+            // Splitting by value drops the back half's leading zeros for free
+            let half = 10u64.pow(digits / 2);
+            return count.call(&(stone / half, blinks - 1))
+                + count.call(&(stone % half, blinks - 1));
+        }
+        count.call(&(stone * 2024, blinks - 1))
+    });
+
+    stones
+        .iter()
+        .map(|stone| count.call(&(*stone, num_blinks)))
+        .sum()
+}
+
+fn read_stones(contents: &str) -> Vec<u64> {
+    contents
+        .split_whitespace()
+        .map(|stone| stone.parse().expect("has to be a number"))
+        .collect()
+}
+
+impl Puzzle for Day11 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+    const TAGS: &'static [Tag] = &[Tag::Dp];
+    const HELPERS: &'static [&'static str] = &["memo"];
+    const NOTES: &'static str = "Memoized stone counts per value and blinks left, so 75 blinks \
+        stay cheap";
+
+    fn puzzle_1(contents: String) -> Answer {
+        let sum = count_stones(&read_stones(&contents), 25);
+        sum.into()
+    }
+
+    fn puzzle_2(contents: String) -> Answer {
+        let sum = count_stones(&read_stones(&contents), 75);
+        sum.into()
+    }
+}
@@ -0,0 +1,87 @@
+use helpers::meta::Tag;
+use helpers::union_find::GridLabels;
+use helpers::{read_grid, Answer, Puzzle};
+
+pub struct Day12;
+
+const SIDES: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+const DIAGONALS: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+
+// Whether the plot `offset` away is in the same region. Regions come from the labels rather than
+// the plant so two separate patches of the same plant never count as one
+fn same_region(regions: &GridLabels, row: usize, col: usize, offset: (isize, isize)) -> bool {
+    match (
+        row.checked_add_signed(offset.0),
+        col.checked_add_signed(offset.1),
+    ) {
+        (Some(other_row), Some(other_col))
+            if other_row < regions.height() && other_col < regions.width() =>
+        {
+            regions.label(other_row, other_col) == regions.label(row, col)
+        }
+        _ => false,
+    }
+}
+
+// A fence goes on every side that faces another region or the edge of the map
+fn perimeter(regions: &GridLabels, row: usize, col: usize) -> usize {
+    SIDES
+        .iter()
+        .filter(|side| !same_region(regions, row, col, **side))
+        .count()
+}
+
+// A region has as many sides as it has corners. Towards each diagonal a plot is an outside
+// corner if neither neighbour that way is in the region, or an inside corner if both are but the
+// diagonal itself isn't
+fn corners(regions: &GridLabels, row: usize, col: usize) -> usize {
+    DIAGONALS
+        .iter()
+        .filter(|(down, right)| {
+            let vertical = same_region(regions, row, col, (*down, 0));
+            let horizontal = same_region(regions, row, col, (0, *right));
+            let diagonal = same_region(regions, row, col, (*down, *right));
+            (!vertical && !horizontal) || (vertical && horizontal && !diagonal)
+        })
+        .count()
+}
+
+fn price(grid: Vec<Vec<char>>, fences: fn(&GridLabels, usize, usize) -> usize) -> usize {
+    let regions = GridLabels::new(&grid, |a, b| a == b);
+    let mut fence_counts = vec![0; regions.count()];
+    for row in 0..regions.height() {
+        for col in 0..regions.width() {
+            fence_counts[regions.label(row, col)] += fences(&regions, row, col);
+        }
+    }
+    fence_counts
+        .into_iter()
+        .enumerate()
+        .map(|(region, fence_count)| {
+            println!(
+                "area: ({}) fence_count: ({fence_count})",
+                regions.size(region)
+            );
+            regions.size(region) * fence_count
+        })
+        .sum()
+}
+
+impl Puzzle for Day12 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Garden Groups";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Graph];
+    const HELPERS: &'static [&'static str] = &["read_grid", "union_find"];
+    const NOTES: &'static str = "Regions labelled with union-find, sides counted as corners";
+
+    fn puzzle_1(contents: String) -> Answer {
+        let grid = read_grid(contents);
+        price(grid, perimeter).into()
+    }
+
+    fn puzzle_2(contents: String) -> Answer {
+        let grid = read_grid(contents);
+        price(grid, corners).into()
+    }
+}
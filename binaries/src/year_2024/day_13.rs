@@ -0,0 +1,255 @@
+use helpers::linear::{self, Solution};
+use helpers::meta::Tag;
+use helpers::{num, Answer, Puzzle, Rational};
+
+pub struct Day13;
+
+const BUTTON_A_START: &str = "Button A:";
+const BUTTON_B_START: &str = "Button B:";
+const PRIZE_START: &str = "Prize:";
+
+#[derive(Debug, Clone)]
+enum Line {
+    ButtonA,
+    ButtonB,
+    Prize,
+}
+
+#[derive(Debug)]
+struct QuestionFailure {
+    line: String,
+    kind: Line,
+}
+
+fn check_line(line_str: &str, kind: Line) -> Result<(i128, i128), QuestionFailure> {
+    let prefix = match kind {
+        Line::ButtonA => BUTTON_A_START,
+        Line::ButtonB => BUTTON_B_START,
+        Line::Prize => PRIZE_START,
+    };
+    if !line_str.starts_with(prefix) {
+        return Err(QuestionFailure {
+            line: line_str.to_string(),
+            kind,
+        });
+    }
+    let str_vals = line_str[prefix.len()..]
+        .trim()
+        .split_once(", ")
+        .ok_or_else(|| QuestionFailure {
+            line: line_str.to_string(),
+            kind: kind.clone(),
+        })?;
+
+    //println!("str_vals: {:?}", str_vals);
+
+    match (str_vals.0[2..].parse(), str_vals.1[2..].parse()) {
+        (Ok(val_1), Ok(val_2)) => Ok((val_1, val_2)),
+        _ => Err(QuestionFailure {
+            line: line_str.to_string(),
+            kind,
+        }),
+    }
+}
+
+#[derive(Debug)]
+struct Question {
+    puzzle_a: (i128, i128),
+    puzzle_b: (i128, i128),
+    prize: (i128, i128),
+}
+
+impl TryFrom<[&str; 3]> for Question {
+    type Error = QuestionFailure;
+
+    fn try_from(value: [&str; 3]) -> Result<Self, Self::Error> {
+        let puzzle_a = check_line(value[0], Line::ButtonA)?;
+        let puzzle_b = check_line(value[1], Line::ButtonB)?;
+        let prize = check_line(value[2], Line::Prize)?;
+        Ok(Question {
+            puzzle_a,
+            puzzle_b,
+            prize,
+        })
+    }
+}
+
+impl Question {
+    fn x1(&self) -> i128 {
+        self.puzzle_a.0
+    }
+    fn y1(&self) -> i128 {
+        self.puzzle_a.1
+    }
+    fn x2(&self) -> i128 {
+        self.puzzle_b.0
+    }
+    fn y2(&self) -> i128 {
+        self.puzzle_b.1
+    }
+    fn xp(&self) -> i128 {
+        self.prize.0
+    }
+    fn yp(&self) -> i128 {
+        self.prize.1
+    }
+
+    fn calc_answer(&self) -> Result<(i128, i128), String> {
+        let buttons = [
+            vec![Rational::from(self.x1()), Rational::from(self.x2())],
+            vec![Rational::from(self.y1()), Rational::from(self.y2())],
+        ];
+        let prize = [Rational::from(self.xp()), Rational::from(self.yp())];
+        match linear::solve(&buttons, &prize).map_err(|err| err.to_string())? {
+            Solution::Unique(presses) => match (presses[0].to_integer(), presses[1].to_integer()) {
+                (Some(n1), Some(n2)) if n1 >= 0 && n2 >= 0 => Ok((n1, n2)),
+                (Some(_), Some(_)) => {
+                    Err("No solution because a press count is negative".to_string())
+                }
+                _ => Err("No solution because the presses aren't whole numbers".to_string()),
+            },
+            Solution::None => {
+                Err("No solution because the buttons can't reach the prize".to_string())
+            }
+            Solution::Infinite(_) => self.cheapest_on_line(),
+        }
+    }
+
+    // Collinear buttons leave a whole line of press counts that reach the prize, so only one
+    // equation matters. The cost changes steadily along the line so the cheapest is at one end
+    // of the stretch where both counts are non-negative whole numbers
+    fn cheapest_on_line(&self) -> Result<(i128, i128), String> {
+        // The x equation is just 0 = 0 when neither button moves along x
+        let (a, b, p) = if self.x1() != 0 || self.x2() != 0 {
+            (self.x1(), self.x2(), self.xp())
+        } else {
+            (self.y1(), self.y2(), self.yp())
+        };
+        let presses = |step: i128| {
+            (p % step == 0 && p / step >= 0)
+                .then_some(p / step)
+                .ok_or_else(|| "No solution because the prize is between the steps".to_string())
+        };
+        // A button that doesn't move along the line doesn't move the claw at all, so it's never
+        // worth paying for
+        match (a, b) {
+            (0, 0) if p == 0 => return Ok((0, 0)),
+            (0, 0) => return Err("No solution because neither button moves the claw".to_string()),
+            (0, _) => return presses(b).map(|n2| (0, n2)),
+            (_, 0) => return presses(a).map(|n1| (n1, 0)),
+            _ => {}
+        }
+
+        let divisor = num::gcd(a, b).ok_or("overflowed")?;
+        if p % divisor != 0 {
+            return Err("No solution because the prize is between the buttons' steps".to_string());
+        }
+
+        // n2 * b ≡ p (mod a) keeps n1 whole
+        let step = a / divisor;
+        let inverse = num::mod_inverse(b / divisor, step).map_err(|err| err.to_string())?;
+        let n2_min = ((p / divisor) % step * inverse).rem_euclid(step);
+        let n2_limit = p / b;
+        if n2_limit < n2_min {
+            return Err("No solution because every way there needs negative presses".to_string());
+        }
+        let n2_max = n2_limit - (n2_limit - n2_min) % step;
+
+        [n2_min, n2_max]
+            .into_iter()
+            .map(|n2| ((p - n2 * b) / a, n2))
+            .min_by_key(|(n1, n2)| 3 * n1 + n2)
+            .ok_or_else(|| "No solution".to_string())
+    }
+}
+
+fn get_questions(contents: String) -> Result<Vec<Question>, QuestionFailure> {
+    let mut line_iter = contents.lines().peekable();
+    // TODO: I think this is an overestimate? Not sure though
+    let mut result = Vec::with_capacity(contents.len() / 3);
+    while line_iter.peek().is_some() {
+        let lines = [
+            line_iter.next().expect("First line has to exist"),
+            line_iter.next().expect("Second line has to exist"),
+            line_iter.next().expect("Third line has to exist"),
+        ];
+        // Skips the blank line
+        line_iter.next();
+        let question: Question = lines
+            .try_into()
+            .unwrap_or_else(|err| panic!("Failed to parse puzzle: {:?}", err));
+        //println!("Question: {:?}", question);
+        result.push(question);
+    }
+    Ok(result)
+}
+
+impl Puzzle for Day13 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+    const TAGS: &'static [Tag] = &[Tag::Math, Tag::Parsing];
+    const HELPERS: &'static [&'static str] = &["linear", "num", "rational"];
+    const NOTES: &'static str = "Each machine is a 2x2 linear system solved exactly, parallel \
+        buttons fall back to the cheapest point on the line";
+
+    fn puzzle_1(contents: String) -> Answer {
+        total_cost(
+            get_questions(contents)
+                .unwrap_or_else(|err| panic!("Failed to generate a question because: {:?}", err)),
+        )
+        .into()
+    }
+
+    fn puzzle_2(contents: String) -> Answer {
+        let mut questions = get_questions(contents)
+            .unwrap_or_else(|err| panic!("Failed to generate a question because: {:?}", err));
+        for question in questions.iter_mut() {
+            question.prize.0 += 10_000_000_000_000;
+            question.prize.1 += 10_000_000_000_000;
+        }
+        total_cost(questions).into()
+    }
+}
+
+// Tokens to win every prize that can be won, the rest are skipped
+fn total_cost(questions: Vec<Question>) -> i128 {
+    questions
+        .iter()
+        .filter_map(|question| question.calc_answer().ok())
+        .map(|(n1, n2)| 3 * n1 + n2)
+        .sum()
+}
+
+#[cfg(test)]
+mod test_day_13 {
+    use helpers::Puzzle;
+
+    use super::{get_questions, Day13};
+
+    fn answers(machines: &str) -> Vec<Result<(i128, i128), String>> {
+        get_questions(machines.to_string())
+            .unwrap()
+            .iter()
+            .map(|question| question.calc_answer())
+            .collect()
+    }
+
+    #[test]
+    fn test_buttons_without_x_steps() {
+        let machine = "Button A: X+0, Y+2\nButton B: X+0, Y+4\nPrize: X=0, Y=8\n";
+        assert_eq!(vec![Ok((0, 2))], answers(machine));
+        assert_eq!("2", Day13::puzzle_1(machine.to_string()).to_string());
+
+        // A button that goes nowhere is never pressed
+        let machine = "Button A: X+0, Y+0\nButton B: X+2, Y+3\nPrize: X=4, Y=6\n";
+        assert_eq!(vec![Ok((0, 2))], answers(machine));
+        let machine = "Button A: X+0, Y+0\nButton B: X+2, Y+3\nPrize: X=5, Y=6\n";
+        assert!(answers(machine)[0].is_err());
+
+        let machine = "Button A: X+0, Y+0\nButton B: X+0, Y+0\nPrize: X=0, Y=0\n";
+        assert_eq!(vec![Ok((0, 0))], answers(machine));
+        let machine = "Button A: X+0, Y+0\nButton B: X+0, Y+0\nPrize: X=0, Y=3\n";
+        assert!(answers(machine)[0].is_err());
+    }
+}
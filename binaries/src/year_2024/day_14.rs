@@ -0,0 +1,382 @@
+use std::cmp::Reverse;
+use std::io::BufRead;
+
+use helpers::cycle;
+use helpers::meta::Tag;
+use helpers::num::{self, Congruence};
+use helpers::params::{self, Param};
+use helpers::progress::Progress;
+use helpers::sim::{Driver, Simulation};
+use helpers::{stream, Answer, BitGrid, Puzzle};
+
+pub struct Day14;
+
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct Robot {
+    starting_pos: (i64, i64),
+    velocity: (i64, i64),
+}
+
+impl From<&str> for Robot {
+    fn from(value: &str) -> Self {
+        let (pos_str, vel_str) = value.split_once(" ").expect("Has to be separate by space");
+
+        let starting_pos_strs = pos_str[2..]
+            .split_once(",")
+            .expect("has to be separate by comma");
+        let velocity_strs = vel_str[2..]
+            .split_once(",")
+            .expect("has to be separate by comma");
+
+        let starting_pos = (
+            starting_pos_strs
+                .0
+                .parse::<i64>()
+                .expect("has to be a number"),
+            starting_pos_strs
+                .1
+                .parse::<i64>()
+                .expect("has to be a number"),
+        );
+        let velocity = (
+            velocity_strs.0.parse::<i64>().expect("has to be a number"),
+            velocity_strs.1.parse::<i64>().expect("has to be a number"),
+        );
+
+        Robot {
+            starting_pos,
+            velocity,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Quadrant {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    MidLines,
+}
+
+impl From<((usize, usize), (usize, usize))> for Quadrant {
+    fn from((pos, bounds): ((usize, usize), (usize, usize))) -> Self {
+        let half_bounds = ((bounds.0 / 2) as usize, (bounds.1 / 2) as usize);
+        let (x_usize, y_usize) = pos;
+        //println!("location is: ({x_usize}, {y_usize})");
+        match (x_usize < half_bounds.0, y_usize < half_bounds.1) {
+            (true, true) => Quadrant::TopLeft,
+            (true, false) if half_bounds.1 != y_usize => Quadrant::BottomLeft,
+            (false, true) if half_bounds.0 != x_usize => Quadrant::TopRight,
+            (false, false) if half_bounds.0 != x_usize && half_bounds.1 != y_usize => {
+                Quadrant::BottomRight
+            }
+            _ => Quadrant::MidLines,
+        }
+    }
+}
+
+impl Quadrant {
+    fn check_quadrant(&self) -> (i32, i32, i32, i32, i32) {
+        match self {
+            Quadrant::TopLeft => (1, 0, 0, 0, 0),
+            Quadrant::TopRight => (0, 1, 0, 0, 0),
+            Quadrant::BottomLeft => (0, 0, 1, 0, 0),
+            Quadrant::BottomRight => (0, 0, 0, 1, 0),
+            Quadrant::MidLines => (0, 0, 0, 0, 1),
+        }
+    }
+}
+
+impl Robot {
+    fn step(&self, coords: (i64, i64), bounds: (i64, i64)) -> (i64, i64) {
+        let (vel_x, vel_y) = self.velocity;
+        let mut x = coords.0 + vel_x;
+        let mut y = coords.1 + vel_y;
+
+        if x < 0 {
+            x = bounds.0 + 1 + x;
+        }
+
+        if y < 0 {
+            y = bounds.1 + 1 + y;
+        }
+
+        if bounds.0 < x {
+            x = x - bounds.0 - 1;
+        }
+
+        if bounds.1 < y {
+            y = y - bounds.1 - 1;
+        }
+
+        (x, y)
+    }
+}
+
+// One robot going round the room, where it is now being the state
+struct Patrol<'a> {
+    robot: &'a Robot,
+    bounds: (i64, i64),
+}
+
+impl Simulation for Patrol<'_> {
+    type State = (i64, i64);
+
+    fn step(&self, pos: &mut (i64, i64)) {
+        *pos = self.robot.step(*pos, self.bounds);
+    }
+}
+
+// Largest x and y a robot can be at. The example runs on an 11x7 room which isn't in the
+// input file, so give it `width=11` and `height=7` in its params
+fn bounds() -> (i64, i64) {
+    (
+        params::int::<i64>("width") - 1,
+        params::int::<i64>("height") - 1,
+    )
+}
+
+impl Puzzle for Day14 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+    const TAGS: &'static [Tag] = &[Tag::Simulation, Tag::Math];
+    const HELPERS: &'static [&'static str] = &[
+        "bit_grid", "cycle", "num", "params", "progress", "sim", "stream",
+    ];
+    const NOTES: &'static str = "Part 1 moves each robot on its own, part 2 lines up the tightest \
+        steps per axis with the CRT and keeps the one that draws the most solid picture";
+    const PARAMS: &'static [Param] = &[Param::int("width", 101), Param::int("height", 103)];
+
+    fn puzzle_1(contents: String) -> Answer {
+        Self::stream_1(&mut contents.as_bytes())
+    }
+
+    fn puzzle_2(contents: String) -> Answer {
+        Self::stream_2(&mut contents.as_bytes())
+    }
+
+    // Robots never run into each other, so each one can be moved on its own as it's read
+    fn stream_1(input: &mut dyn BufRead) -> Answer {
+        let bounds = bounds();
+        let mut progress = Progress::counting("robots moved");
+        let quadrant_counts = stream::lines(input)
+            .map(|line| Robot::from(line.as_str()))
+            .fold((0, 0, 0, 0, 0), |acc, robot| {
+                let mut patrol = Driver::new(
+                    Patrol {
+                        robot: &robot,
+                        bounds,
+                    },
+                    robot.starting_pos,
+                );
+                patrol.run(100);
+                let pos = *patrol.state();
+                let outcome = Quadrant::from((
+                    (pos.0 as usize, pos.1 as usize),
+                    (bounds.0 as usize, bounds.1 as usize),
+                ));
+                progress.inc();
+                let check_quadrant = outcome.check_quadrant();
+                (
+                    acc.0 + check_quadrant.0,
+                    acc.1 + check_quadrant.1,
+                    acc.2 + check_quadrant.2,
+                    acc.3 + check_quadrant.3,
+                    acc.4 + check_quadrant.4,
+                )
+            });
+        let result = quadrant_counts.0 * quadrant_counts.1 * quadrant_counts.2 * quadrant_counts.3;
+        result.into()
+    }
+
+    // Every robot is needed at every step, but there's no need to hold the text as well
+    fn stream_2(input: &mut dyn BufRead) -> Answer {
+        let bounds = bounds();
+        let robots = stream::lines(input)
+            .map(|line| Robot::from(line.as_str()))
+            .collect::<Vec<_>>();
+
+        // Each axis wraps on its own, so has a period of its own. The tree is where the robots
+        // bunch up the most on both, so find the tightest steps for each axis and line them up
+        let (width, height) = (bounds.0 + 1, bounds.1 + 1);
+        let x_axis = |robot: &Robot| (robot.starting_pos.0, robot.velocity.0);
+        let y_axis = |robot: &Robot| (robot.starting_pos.1, robot.velocity.1);
+        let x_period = axis_period(&robots, width, x_axis);
+        let y_period = axis_period(&robots, height, y_axis);
+        let mut progress = Progress::new("steps checked", (x_period + y_period) as usize);
+        let x_steps = tightest_steps(&robots, width, x_period, &mut progress, x_axis);
+        let y_steps = tightest_steps(&robots, height, y_period, &mut progress, y_axis);
+        let candidates = x_steps.iter().flat_map(|x_step| {
+            y_steps.iter().map(|y_step| {
+                num::crt(&[
+                    Congruence::new(*x_step, x_period),
+                    Congruence::new(*y_step, y_period),
+                ])
+                .unwrap_or_else(|err| panic!("x and y can't line up: {err}"))
+                .residue
+            })
+        });
+        // Bunching up isn't the same as drawing something, so check the picture too. Ties go to
+        // the tightest
+        let tree = candidates
+            .min_by_key(|step| Reverse(solid_robots(&robots, *step, (width, height))))
+            .expect("there's always a tightest step");
+        tree.into()
+    }
+}
+
+// How many of the tightest steps on each axis get lined up and checked
+const CANDIDATES: usize = 3;
+
+// How many steps it takes every robot to be back where it started along one axis, `size` unless
+// all their velocities share a factor with it
+fn axis_period(robots: &[Robot], size: i64, axis: impl Fn(&Robot) -> (i64, i64)) -> i64 {
+    let start = robots.iter().map(|robot| axis(robot).0).collect::<Vec<_>>();
+    let next = |positions: &Vec<i64>| {
+        positions
+            .iter()
+            .zip(robots)
+            .map(|(pos, robot)| (pos + axis(robot).1).rem_euclid(size))
+            .collect()
+    };
+    cycle::brent(&start, next).length as i64
+}
+
+// The `CANDIDATES` steps in `0..period` where the robots' positions along one axis, `size` long,
+// have the lowest variance, tightest first
+fn tightest_steps(
+    robots: &[Robot],
+    size: i64,
+    period: i64,
+    progress: &mut Progress,
+    axis: impl Fn(&Robot) -> (i64, i64),
+) -> Vec<i64> {
+    let variance = |step: i64| {
+        let positions = robots
+            .iter()
+            .map(|robot| {
+                let (pos, vel) = axis(robot);
+                (pos + vel * step).rem_euclid(size)
+            })
+            .collect::<Vec<_>>();
+        let mean = positions.iter().sum::<i64>() as f64 / positions.len() as f64;
+        positions
+            .iter()
+            .map(|pos| (*pos as f64 - mean).powi(2))
+            .sum::<f64>()
+    };
+    let mut steps = (0..period)
+        .map(|step| {
+            progress.inc();
+            (step, variance(step))
+        })
+        .collect::<Vec<_>>();
+    steps.sort_by(|a, b| a.1.total_cmp(&b.1));
+    steps
+        .into_iter()
+        .take(CANDIDATES)
+        .map(|(step, _)| step)
+        .collect()
+}
+
+// How many spots have a robot with robots on all 4 sides at `step`. The tree is drawn solid so
+// it has plenty, robots scattered at random next to none
+fn solid_robots(robots: &[Robot], step: i64, (width, height): (i64, i64)) -> usize {
+    let mut grid = BitGrid::new(width as usize, height as usize);
+    for robot in robots {
+        let x = (robot.starting_pos.0 + robot.velocity.0 * step).rem_euclid(width);
+        let y = (robot.starting_pos.1 + robot.velocity.1 * step).rem_euclid(height);
+        grid.set(y as usize, x as usize, true);
+    }
+    grid.erode().count_ones()
+}
+
+#[cfg(test)]
+mod test_day_14 {
+    use helpers::params::{self, Params};
+    use helpers::Puzzle;
+
+    use super::{axis_period, Day14, Robot};
+
+    const EXAMPLE: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    #[test]
+    fn test_example() {
+        let mut example = Params::defaults(Day14::PARAMS);
+        example
+            .apply(&[
+                ("width".to_string(), "11".to_string()),
+                ("height".to_string(), "7".to_string()),
+            ])
+            .unwrap();
+        let answer = params::with(example, || Day14::puzzle_1(EXAMPLE.to_string()));
+        assert_eq!("12", answer.to_string());
+    }
+
+    #[test]
+    fn test_axis_period() {
+        let robots = ["p=0,0 v=2,1", "p=3,1 v=4,-3"].map(Robot::from);
+        assert_eq!(
+            3,
+            axis_period(&robots, 6, |robot| (robot.starting_pos.0, robot.velocity.0))
+        );
+        assert_eq!(
+            6,
+            axis_period(&robots, 6, |robot| (robot.starting_pos.1, robot.velocity.1))
+        );
+    }
+
+    // A framed tree drawn at step 6577 among robots scattered at random, moved back to where
+    // they'd start from
+    #[test]
+    fn test_finds_the_tree() {
+        let (width, height, tree) = (101, 103, 6577);
+        let mut seed = 0x2024_u64;
+        let mut random = |below: i64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % below as u64) as i64
+        };
+
+        let mut picture = Vec::new();
+        for y in 0..33 {
+            for x in 0..31_i64 {
+                let frame = x == 0 || x == 30 || y == 0 || y == 32;
+                let branches = (y - 2) / 2 + 1;
+                let trunk = y >= 28 && (13..=17).contains(&x);
+                if frame || ((2..28).contains(&y) && (15 - x).abs() < branches) || trunk {
+                    picture.push((x + 40, y + 30));
+                }
+            }
+        }
+        let scattered = (0..200).map(|_| (random(width), random(height)));
+        let input = picture
+            .into_iter()
+            .chain(scattered.collect::<Vec<_>>())
+            .map(|(x, y)| {
+                let (vx, vy) = (random(201) - 100, random(201) - 100);
+                let start_x = (x - vx * tree).rem_euclid(width);
+                let start_y = (y - vy * tree).rem_euclid(height);
+                format!("p={start_x},{start_y} v={vx},{vy}\n")
+            })
+            .collect::<String>();
+
+        let answer = params::with(Params::defaults(Day14::PARAMS), || Day14::puzzle_2(input));
+        assert_eq!(tree.to_string(), answer.to_string());
+    }
+}
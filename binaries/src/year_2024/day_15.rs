@@ -1,12 +1,11 @@
 use std::collections::HashSet;
+use std::marker::PhantomData;
 
-use helpers::{read_grid, Puzzle};
+use helpers::meta::Tag;
+use helpers::sim::{Driver, Simulation};
+use helpers::{read_grid, Answer, Puzzle};
 
-fn main() {
-    Day15::run();
-}
-
-struct Day15;
+pub struct Day15;
 
 #[derive(Clone, Debug)]
 enum Direction {
@@ -492,13 +491,22 @@ fn print_grid<T: PrintSpace + Clone>(grid: Vec<Vec<T>>) {
     }
 }
 
-fn run_sim(start_coords: (usize, usize), moves: Vec<Direction>, grid: &mut Vec<Vec<Space>>) {
-    let mut curr_coords = start_coords;
-
-    for next_move in moves {
-        //println!("Current move is: {:?}", next_move);
-        //print_grid(grid.clone());
+trait Push: Sized {
+    // Moves the robot at `robot` one space, pushing any boxes in the way, and returns where the
+    // robot ends up. It stays put if the boxes are up against an edge
+    fn push(
+        grid: &mut Vec<Vec<Self>>,
+        robot: (usize, usize),
+        direction: Direction,
+    ) -> (usize, usize);
+}
 
+impl Push for Space {
+    fn push(
+        grid: &mut Vec<Vec<Self>>,
+        curr_coords: (usize, usize),
+        next_move: Direction,
+    ) -> (usize, usize) {
         let empty_space = find_empty_space(curr_coords, next_move.clone(), &grid);
 
         if let Some(mut move_into_coords) = empty_space {
@@ -516,22 +524,18 @@ fn run_sim(start_coords: (usize, usize), moves: Vec<Direction>, grid: &mut Vec<V
                 move_into_coords = move_coords;
             }
             grid[curr_coords.0][curr_coords.1] = Space::Empty;
-            curr_coords = next_move.update_coords(curr_coords);
+            return next_move.update_coords(curr_coords);
         }
+        curr_coords
     }
 }
 
-fn run_sim_2(
-    start_coords: (usize, usize),
-    moves: Vec<Direction>,
-    grid: &mut Vec<Vec<DoubleSpace>>,
-) {
-    let mut curr_coords = start_coords;
-
-    for next_move in moves {
-        //println!("Current move is: {:?}", next_move);
-        //print_grid(grid.clone());
-
+impl Push for DoubleSpace {
+    fn push(
+        grid: &mut Vec<Vec<Self>>,
+        curr_coords: (usize, usize),
+        next_move: Direction,
+    ) -> (usize, usize) {
         let boxes_to_move_opt = boxes_to_move(curr_coords, next_move.clone(), &grid);
 
         if let Some(boxes_to_move) = boxes_to_move_opt {
@@ -539,9 +543,6 @@ fn run_sim_2(
             // other
             let sorted_boxes = next_move.sort_boxes(boxes_to_move);
             // the blocks to move are the coords of the left block
-            if sorted_boxes.len() >= 1 {
-                //println!("moving boxes");
-            }
             for left_box in sorted_boxes {
                 let right_box = (left_box.0, left_box.1 + 1);
                 grid[left_box.0][left_box.1] = DoubleSpace::Empty;
@@ -553,11 +554,58 @@ fn run_sim_2(
                 grid[updated_right_box.0][updated_right_box.1] = DoubleSpace::RightBox;
             }
             grid[curr_coords.0][curr_coords.1] = DoubleSpace::Empty;
-            curr_coords = next_move.update_coords(curr_coords);
+            let curr_coords = next_move.update_coords(curr_coords);
             grid[curr_coords.0][curr_coords.1] = DoubleSpace::Robot;
+            return curr_coords;
         }
+        curr_coords
+    }
+}
+
+// The robot working through its list of moves, done once it's made them all
+struct Warehouse<T> {
+    moves: Vec<Direction>,
+    spaces: PhantomData<T>,
+}
+
+#[derive(Clone)]
+struct Floor<T> {
+    grid: Vec<Vec<T>>,
+    robot: (usize, usize),
+    next_move: usize,
+}
+
+impl<T: Push + Clone> Simulation for Warehouse<T> {
+    type State = Floor<T>;
+
+    fn step(&self, floor: &mut Floor<T>) {
+        let next_move = self.moves[floor.next_move].clone();
+        floor.robot = T::push(&mut floor.grid, floor.robot, next_move);
+        floor.next_move += 1;
     }
-    //print_grid(grid.clone());
+
+    fn is_done(&self, floor: &Floor<T>) -> bool {
+        floor.next_move == self.moves.len()
+    }
+}
+
+fn run_sim<T: Push + Clone>(
+    start_coords: (usize, usize),
+    moves: Vec<Direction>,
+    grid: Vec<Vec<T>>,
+) -> Vec<Vec<T>> {
+    let warehouse = Warehouse {
+        moves,
+        spaces: PhantomData,
+    };
+    let floor = Floor {
+        grid,
+        robot: start_coords,
+        next_move: 0,
+    };
+    let mut driver = Driver::new(warehouse, floor);
+    driver.run_to_end();
+    driver.into_state().grid
 }
 
 fn get_score<T: Scorable>(grid: Vec<Vec<T>>) -> usize {
@@ -577,9 +625,17 @@ fn get_score<T: Scorable>(grid: Vec<Vec<T>>) -> usize {
 }
 
 impl Puzzle for Day15 {
-    fn puzzle_1(contents: String) {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Warehouse Woes";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Simulation];
+    const HELPERS: &'static [&'static str] = &["read_grid", "sim"];
+    const NOTES: &'static str = "Steps through every move, part 2 pushes whole groups of wide \
+        boxes at once";
+
+    fn puzzle_1(contents: String) -> Answer {
         let mut start_coords = (0, 0);
-        let (mut grid, moves) = read_grid(contents).into_iter().enumerate().fold(
+        let (grid, moves) = read_grid(contents).into_iter().enumerate().fold(
             (Vec::<Vec<Space>>::new(), Vec::<Direction>::new()),
             |(mut acc_grid, mut acc_moves), (row_idx, row)| {
                 let mut row_iter = row.into_iter().peekable();
@@ -597,15 +653,15 @@ impl Puzzle for Day15 {
             },
         );
 
-        run_sim(start_coords, moves, &mut grid);
+        let grid = run_sim(start_coords, moves, grid);
 
         let score = get_score(grid);
-        println!("Score is: {score}");
+        score.into()
     }
 
-    fn puzzle_2(contents: String) {
+    fn puzzle_2(contents: String) -> Answer {
         let mut start_coords = (0, 0);
-        let (mut grid, moves) = read_grid(contents).into_iter().enumerate().fold(
+        let (grid, moves) = read_grid(contents).into_iter().enumerate().fold(
             (Vec::<Vec<DoubleSpace>>::new(), Vec::<Direction>::new()),
             |(mut acc_grid, mut acc_moves), (row_idx, row)| {
                 let mut row_iter = row.into_iter().peekable();
@@ -623,9 +679,9 @@ impl Puzzle for Day15 {
             },
         );
 
-        run_sim_2(start_coords, moves, &mut grid);
+        let grid = run_sim(start_coords, moves, grid);
 
         let score = get_score(grid);
-        println!("Score is: {score}");
+        score.into()
     }
 }
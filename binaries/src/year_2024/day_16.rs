@@ -1,13 +1,11 @@
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::collections::BinaryHeap;
 use std::hash::Hash;
 
-use helpers::{read_grid, Puzzle};
+use helpers::hash::{FastMap, FastSet};
+use helpers::meta::Tag;
+use helpers::{read_grid, Answer, Puzzle};
 
-struct Day16;
-
-fn main() {
-    Day16::run()
-}
+pub struct Day16;
 
 #[derive(Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 enum Direction {
@@ -136,7 +134,7 @@ impl<'a> Iterator for CreateEdgeIterator<'a> {
 struct Graph {
     start: Coord,
     end: Coord,
-    edges: HashMap<DirectionalCoord, Vec<Edge>>,
+    edges: FastMap<DirectionalCoord, Vec<Edge>>,
 }
 
 #[derive(Eq, PartialEq, Hash, Debug, Clone, Default)]
@@ -209,7 +207,7 @@ impl Graph {
         self.edges.entry(key).or_insert_with(Vec::new).push(edge);
     }
 
-    fn shortest_path(self, grid: Vec<Vec<char>>) -> (usize, HashSet<Coord>) {
+    fn shortest_path(self, grid: Vec<Vec<char>>) -> (usize, FastSet<Coord>) {
         let mut unvisited = IndexedBinaryHeap::new();
         for key in self.edges.keys() {
             if key.0 == self.start {
@@ -223,7 +221,7 @@ impl Graph {
             }
         }
 
-        let mut locations = HashSet::new();
+        let mut locations = FastSet::default();
         let mut answer = usize::MAX;
         let mut i = 0;
         unvisited.print_binary_tree();
@@ -272,14 +270,14 @@ impl Graph {
 #[derive(Clone)]
 struct IndexedBinaryHeap<T: Ord + Hash + Default + Clone, H: Ord + Hash> {
     values: Vec<HeapNode<T, H>>,
-    indeces: HashMap<DirectionalCoord, usize>,
+    indeces: FastMap<DirectionalCoord, usize>,
 }
 
 impl IndexedBinaryHeap<DirectionalCoord, Coord> {
     fn new() -> Self {
         let mut values = Vec::new();
         values.push(HeapNode::new(DirectionalCoord::default()));
-        let indeces = HashMap::new();
+        let indeces = FastMap::default();
         Self { values, indeces }
     }
 
@@ -460,7 +458,7 @@ enum DecrementKeyResult {
 mod test_heap {
     use std::sync::atomic::{AtomicUsize, Ordering};
 
-    use crate::{
+    use super::{
         Coord, DecrementKeyResult, Direction, DirectionalCoord, HeapNode, IndexedBinaryHeap,
     };
 
@@ -537,7 +535,7 @@ mod test_heap {
     }
 }
 
-fn print_grid_fill(locations: &HashSet<Coord>, grid: Vec<Vec<char>>) {
+fn print_grid_fill(locations: &FastSet<Coord>, grid: Vec<Vec<char>>) {
     for (row_idx, row) in grid.into_iter().enumerate() {
         for (col_idx, col) in row.into_iter().enumerate() {
             let coord = (row_idx, col_idx).into();
@@ -558,8 +556,8 @@ fn print_grid_with_costs(heap: IndexedBinaryHeap<DirectionalCoord, Coord>, grid:
         .filter(|node| node.priority < usize::MAX)
         .map(|node| (node.value, node.priority))
         .fold(
-            HashMap::new(),
-            |mut acc: HashMap<Coord, (usize, Direction)>, val| {
+            FastMap::default(),
+            |mut acc: FastMap<Coord, (usize, Direction)>, val| {
                 if let Some(curr) = acc.get(&val.0 .0) {
                     if val.1 < curr.0 {
                         acc.insert(val.0 .0, (val.1, val.0 .1));
@@ -660,7 +658,7 @@ impl TryFrom<Vec<Vec<char>>> for Graph {
             let mut graph = Self {
                 start: start.clone(),
                 end: end.clone(),
-                edges: HashMap::new(),
+                edges: FastMap::default(),
             };
 
             //println!("start: {:?}, end: {:?}", start, end);
@@ -688,7 +686,15 @@ impl TryFrom<Vec<Vec<char>>> for Graph {
 }
 
 impl Puzzle for Day16 {
-    fn puzzle_1(contents: String) {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 16;
+    const TITLE: &'static str = "Reindeer Maze";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Graph];
+    const HELPERS: &'static [&'static str] = &["hash", "read_grid"];
+    const NOTES: &'static str = "Dijkstra over position and facing with turn costs, keeping every \
+        best path for part 2";
+
+    fn puzzle_1(contents: String) -> Answer {
         let grid = read_grid(contents);
         let graph = Graph::try_from(grid.clone()).expect("has to be a graph or there's an issue");
         let cost = graph.shortest_path(grid.clone());
@@ -698,9 +704,13 @@ impl Puzzle for Day16 {
             cost.1.len()
         );
         println!("all locations: {:?}", cost.1);
+        cost.0.into()
     }
 
-    fn puzzle_2(contents: String) {
-        Self::puzzle_1(contents);
+    fn puzzle_2(contents: String) -> Answer {
+        let grid = read_grid(contents);
+        let graph = Graph::try_from(grid.clone()).expect("has to be a graph or there's an issue");
+        let cost = graph.shortest_path(grid.clone());
+        cost.1.len().into()
     }
 }
@@ -1,11 +1,9 @@
-use std::{
-    collections::{HashMap, HashSet},
-    ops::Deref,
-};
+use std::collections::{HashMap, HashSet};
 
-use helpers::Puzzle;
+use helpers::meta::Tag;
+use helpers::{Answer, BitVec, Puzzle};
 
-struct Day17;
+pub struct Day17;
 
 #[derive(Debug, Clone)]
 struct Computer {
@@ -192,121 +190,23 @@ fn get_program_out_string(mut computer: Computer) -> String {
     computer.out_string()
 }
 
-// Claude basically wrote this function
-fn convert_to_bits(num: usize, len: usize) -> Vec<Bit> {
-    // Get number of bits needed to represent the number
-    let bits_needed = if num == 0 {
-        return vec![Bit::Zero; len];
-    } else {
-        (usize::BITS - num.leading_zeros()) as usize
-    };
-
-    let mut bits = Vec::with_capacity(bits_needed);
-    let mut n = num;
-
-    // Extract bits from right to left
-    while n > 0 {
-        let bit = match n & 1 {
-            0 => Bit::Zero,
-            1 => Bit::One,
-            _ => panic!("this is impossible"),
-        };
-        bits.push(bit);
-        n >>= 1;
-    }
-
-    // If number was 0, push a single 0
-    if bits.is_empty() {
-        bits.push(Bit::Zero);
-    }
-
-    // Include leading zeros up to length
-    while bits.len() < len {
-        bits.push(Bit::Zero);
-    }
-    // Reverse to get most significant bits first
-    bits.reverse();
-    assert!(bits.len() == len);
-    bits
-}
-
-// Claude basically wrote this function too
-fn bitvec_to_usize(bits: &Vec<Bit>) -> usize {
-    let mut result: usize = 0;
-
-    for bit in bits.iter() {
-        result = (result << 1) | **bit;
-    }
-
-    result
-}
-
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
-enum Bit {
-    One,
-    Zero,
-}
-
-impl Deref for Bit {
-    type Target = usize;
-
-    fn deref(&self) -> &Self::Target {
-        match self {
-            Bit::One => &1,
-            Bit::Zero => &0,
-        }
-    }
-}
-
-struct PrefixLockBitVec {
-    current_new_val: usize,
-    prefix_bits: Vec<Bit>,
-    playground_bits: Vec<Bit>,
-}
-
-impl Iterator for PrefixLockBitVec {
-    type Item = (usize, Vec<Bit>);
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current_new_val > 7 {
-            return None;
-        }
-
-        let suffix_bits = convert_to_bits(self.current_new_val, 3);
-        self.playground_bits.truncate(self.prefix_bits.len());
-        for bit in suffix_bits.iter() {
-            self.playground_bits.push(bit.clone());
-        }
-        assert!(self.playground_bits.len() == self.prefix_bits.len() + 3);
-        let current = bitvec_to_usize(&self.playground_bits);
-
-        self.current_new_val += 1;
-
-        Some((current, suffix_bits))
-    }
-}
-
-impl PrefixLockBitVec {
-    fn new(prefix_bits: Vec<Bit>) -> Self {
-        let mut playground_bits = Vec::with_capacity(prefix_bits.len());
-        playground_bits.clone_from(&prefix_bits);
-        Self {
-            current_new_val: 0,
-            playground_bits,
-            prefix_bits,
-        }
-    }
-}
-
 impl Puzzle for Day17 {
-    fn puzzle_1(contents: String) {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 17;
+    const TITLE: &'static str = "Chronospatial Computer";
+    const TAGS: &'static [Tag] = &[Tag::Vm];
+    const HELPERS: &'static [&'static str] = &["bit_vec"];
+    const NOTES: &'static str = "Runs the 3-bit VM, part 2 builds A three bits per output digit \
+        and backtracks on a mismatch";
+
+    fn puzzle_1(contents: String) -> Answer {
         let computer: Computer = contents.into();
         println!("computer is: {:?}", computer);
         let out = get_program_out_string(computer);
-        println!("out: {out}");
+        out.into()
     }
 
-    fn puzzle_2(contents: String) {
+    fn puzzle_2(contents: String) -> Answer {
         let computer: Computer = contents.into();
         let target = computer
             .code
@@ -316,12 +216,14 @@ impl Puzzle for Day17 {
             .collect::<Vec<_>>();
 
         let mut matched_target = false;
-        let mut current_bits = Vec::with_capacity(46);
+        let mut current_bits = BitVec::default();
         let mut matched_previously = 0;
-        let mut skip_map: HashMap<usize, HashSet<Vec<Bit>>> = HashMap::new();
+        let mut skip_map: HashMap<usize, HashSet<BitVec>> = HashMap::new();
 
         while !matched_target {
-            let iter = PrefixLockBitVec::new(current_bits.clone());
+            // Each output digit comes from 3 more bits of A, so lock in the bits that matched so
+            // far and try every 3 bit suffix after them
+            let iter = current_bits.with_suffixes(3);
             match find_match(
                 matched_previously,
                 &target,
@@ -329,19 +231,16 @@ impl Puzzle for Day17 {
                 iter,
                 computer.clone(),
             ) {
-                Some((output, mut bits)) => {
+                Some((output, bits)) => {
                     matched_previously += 1;
                     matched_target = output.len() == target.len();
-                    current_bits.append(&mut bits);
+                    current_bits.push_suffix(&bits);
                 }
                 None => {
                     println!(
                         "Couldn't find any in current state, going back one level and tryin again"
                     );
-                    let to_skip = current_bits
-                        .drain(current_bits.len() - 3..)
-                        .collect::<Vec<_>>();
-                    assert!(to_skip.len() == 3);
+                    let to_skip = current_bits.split_off_suffix(3);
                     // Clear the current level since we're going back up and will start from a
                     // different place
                     skip_map
@@ -356,18 +255,22 @@ impl Puzzle for Day17 {
                 }
             }
         }
-        println!("num: {}", bitvec_to_usize(&current_bits));
+        current_bits
+            .to_int::<usize>()
+            .expect("A has to fit in a usize")
+            .into()
     }
 }
 
 fn find_match(
     matched_previously: usize,
     target: &Vec<usize>,
-    skip: &HashMap<usize, HashSet<Vec<Bit>>>,
-    mut iter: PrefixLockBitVec,
+    skip: &HashMap<usize, HashSet<BitVec>>,
+    iter: impl Iterator<Item = BitVec>,
     computer: Computer,
-) -> Option<(Vec<usize>, Vec<Bit>)> {
-    while let Some((val, bits)) = iter.next() {
+) -> Option<(Vec<usize>, BitVec)> {
+    for whole in iter {
+        let bits = whole.suffix(3);
         if let Some(set) = skip.get(&matched_previously) {
             if set.contains(&bits) {
                 println!("skipping!");
@@ -375,7 +278,7 @@ fn find_match(
             }
         }
         let mut test_computer = computer.clone();
-        test_computer.a = val;
+        test_computer.a = whole.to_int().expect("A has to fit in a usize");
         test_computer.run_program();
         let output = test_computer
             .out
@@ -399,7 +302,3 @@ fn find_match(
     }
     None
 }
-
-fn main() {
-    Day17::run();
-}
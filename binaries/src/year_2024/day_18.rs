@@ -0,0 +1,245 @@
+use std::collections::VecDeque;
+
+use helpers::hash::FastMap;
+use helpers::meta::Tag;
+use helpers::params::{self, Param};
+use helpers::union_find::UnionFind;
+use helpers::{Answer, BitGrid, Puzzle};
+
+pub struct Day18;
+
+#[derive(Clone, Eq, PartialEq, Hash)]
+struct Space {
+    count: Option<usize>,
+    kind: SpaceKind,
+}
+
+impl Space {
+    fn blocked(&mut self) -> bool {
+        if !matches!(self.kind, SpaceKind::Blocked) {
+            self.kind = SpaceKind::Blocked;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn should_visit(&self) -> bool {
+        match self.kind {
+            SpaceKind::Empty => self.count.is_none(),
+            SpaceKind::Blocked => false,
+        }
+    }
+
+    fn set_count(&mut self, count: usize) {
+        self.count = Some(count);
+    }
+}
+
+impl Default for Space {
+    fn default() -> Self {
+        Self {
+            count: None,
+            kind: SpaceKind::Empty,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash)]
+enum SpaceKind {
+    Empty,
+    Blocked,
+}
+
+fn coords_vec(contents: String) -> Vec<(usize, usize)> {
+    contents
+        .lines()
+        .map(|line| line.split_once(",").expect("has to have a comma"))
+        .map(|(x, y)| {
+            (
+                y.parse().expect("has to be a usize"),
+                x.parse().expect("has to be a usize"),
+            )
+        })
+        .collect::<Vec<_>>()
+}
+
+fn read_coords(
+    coords: impl Iterator<Item = (usize, usize)>,
+    size: usize,
+) -> (Vec<Vec<Space>>, usize) {
+    let mut num_blocked = 0;
+    let result = coords.fold(
+        vec![vec![Space::default(); size]; size],
+        |mut grid, (row, col): (usize, usize)| {
+            if grid[row][col].blocked() {
+                num_blocked += 1;
+            }
+            grid
+        },
+    );
+
+    (result, num_blocked)
+}
+
+fn expand_search(grid: &mut Vec<Vec<Space>>, coords: (usize, usize)) -> Vec<(usize, usize)> {
+    let mut result = Vec::with_capacity(4);
+
+    if coords.0 > 0 && grid[coords.0 - 1][coords.1].should_visit() {
+        result.push((coords.0 - 1, coords.1));
+    }
+    if coords.1 > 0 && grid[coords.0][coords.1 - 1].should_visit() {
+        result.push((coords.0, coords.1 - 1));
+    }
+    if coords.0 < grid.len() - 1 && grid[coords.0 + 1][coords.1].should_visit() {
+        result.push((coords.0 + 1, coords.1));
+    }
+    if coords.1 < grid[coords.0].len() - 1 && grid[coords.0][coords.1 + 1].should_visit() {
+        result.push((coords.0, coords.1 + 1));
+    }
+
+    result
+}
+
+fn find_shortest_path(grid: &mut Vec<Vec<Space>>) -> Result<usize, ()> {
+    let mut to_search = VecDeque::new();
+    grid[0][0].set_count(0);
+    to_search.push_back((0, 0));
+    while let Some(coords) = to_search.pop_front() {
+        let current_count = grid[coords.0][coords.1]
+            .count
+            .expect("current space has to have a count");
+        if coords.0 == grid.len() - 1 && coords.1 == grid[coords.0].len() - 1 {
+            return Ok(current_count);
+        }
+        expand_search(grid, coords)
+            .into_iter()
+            .for_each(|(row, col)| {
+                grid[row][col].set_count(current_count + 1);
+                to_search.push_back((row, col));
+            });
+        print_grid(grid.clone());
+    }
+    Err(())
+}
+
+// Joins the open cell with each open neighbour, cells numbered row by row
+fn join_open_neighbours(sets: &mut UnionFind, blocked: &BitGrid, (row, col): (usize, usize)) {
+    let size = blocked.width();
+    let neighbours = [
+        row.checked_sub(1).map(|up| (up, col)),
+        col.checked_sub(1).map(|left| (row, left)),
+        (row + 1 < size).then_some((row + 1, col)),
+        (col + 1 < size).then_some((row, col + 1)),
+    ];
+    for (other_row, other_col) in neighbours.into_iter().flatten() {
+        if !blocked.get(other_row, other_col) {
+            sets.union(row * size + col, other_row * size + other_col);
+        }
+    }
+}
+
+impl Puzzle for Day18 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 18;
+    const TITLE: &'static str = "RAM Run";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Graph];
+    const HELPERS: &'static [&'static str] = &["bit_grid", "hash", "params", "union_find"];
+    const NOTES: &'static str =
+        "BFS for part 1, part 2 adds bytes back in reverse with union-find \
+        until start and end join";
+    // The example is a 7x7 grid with 12 bytes fallen for part 1
+    const PARAMS: &'static [Param] = &[Param::int("size", 71), Param::int("bytes", 1024)];
+
+    fn puzzle_1(contents: String) -> Answer {
+        let coords_vec = coords_vec(contents);
+        let (mut grid, _) = read_coords(
+            coords_vec.into_iter().take(params::int("bytes")),
+            params::int("size"),
+        );
+
+        let steps = find_shortest_path(&mut grid).expect("has to find end");
+        steps.into()
+    }
+
+    fn puzzle_2(contents: String) -> Answer {
+        let coords = coords_vec(contents);
+        let size = params::int::<usize>("size");
+        let mut blocked = BitGrid::new(size, size);
+        let mut first_fell: FastMap<(usize, usize), usize> = FastMap::default();
+        for (idx, coord) in coords.iter().enumerate() {
+            blocked.set(coord.0, coord.1, true);
+            first_fell.entry(*coord).or_insert(idx);
+        }
+
+        // Work backwards from every byte having fallen, lifting them latest first. The first one
+        // whose removal joins the start to the exit is the one that cut it off
+        let mut sets = UnionFind::new(size * size);
+        for row in 0..size {
+            for col in 0..size {
+                if !blocked.get(row, col) {
+                    join_open_neighbours(&mut sets, &blocked, (row, col));
+                }
+            }
+        }
+        let exit = size * size - 1;
+        for (idx, coord) in coords.iter().enumerate().rev() {
+            // A byte landing on one that's already there doesn't free anything up
+            if first_fell[coord] != idx {
+                continue;
+            }
+            blocked.set(coord.0, coord.1, false);
+            join_open_neighbours(&mut sets, &blocked, *coord);
+            if sets.connected(0, exit) {
+                // Stored as (row, col) but the puzzle wants it as X,Y
+                return format!("{},{}", coord.1, coord.0).into();
+            }
+        }
+        panic!("every byte fell and the exit was never cut off")
+    }
+}
+
+fn print_grid(grid: Vec<Vec<Space>>) {
+    println!("===Start===");
+    for row in grid {
+        for space in row {
+            match (space.count, space.kind) {
+                (Some(_), SpaceKind::Empty) => print!("O"),
+                (None, SpaceKind::Empty) => print!("."),
+                (_, SpaceKind::Blocked) => print!("#"),
+            }
+        }
+        println!("");
+    }
+    println!("===End===");
+}
+
+#[cfg(test)]
+mod test_day_18 {
+    use helpers::params::{self, Params};
+    use helpers::Puzzle;
+
+    use super::Day18;
+
+    const EXAMPLE: &str = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n\
+        5,5\n2,5\n6,5\n1,4\n0,4\n6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0\n";
+
+    fn example_params() -> Params {
+        let mut params = Params::defaults(Day18::PARAMS);
+        params
+            .apply(&[
+                ("size".to_string(), "7".to_string()),
+                ("bytes".to_string(), "12".to_string()),
+            ])
+            .unwrap();
+        params
+    }
+
+    #[test]
+    fn test_example() {
+        params::with(example_params(), || {
+            assert_eq!("22", Day18::puzzle_1(EXAMPLE.to_string()).to_string());
+            assert_eq!("6,1", Day18::puzzle_2(EXAMPLE.to_string()).to_string());
+        });
+    }
+}
@@ -0,0 +1,109 @@
+use helpers::memo::Memoized;
+use helpers::meta::Tag;
+use helpers::{Answer, Puzzle, Trie};
+
+pub struct Day19;
+
+struct Spa {
+    towels: Trie<u8>,
+    designs: Vec<String>,
+}
+
+impl From<String> for Spa {
+    fn from(val: String) -> Self {
+        let mut lines = val.lines();
+        let towel_line = lines.next().expect("has to have at least one line");
+        let towels = towel_line.split(", ").fold(Trie::new(), |mut acc, val| {
+            acc.insert(val.bytes());
+            acc
+        });
+
+        // skip the blank line
+        lines.next();
+
+        let designs = lines
+            .into_iter()
+            .map(|val| val.to_string())
+            .collect::<Vec<_>>();
+
+        Self { towels, designs }
+    }
+}
+
+impl Spa {
+    fn count_valid_designs(self) -> usize {
+        //println!("towels: {:?}", self.towels);
+        self.designs
+            .iter()
+            .enumerate()
+            .filter(|(idx, design_str)| {
+                println!("{idx}");
+                println!("design_str: {design_str}");
+                let mut potentials = Vec::<&str>::new();
+                self.get_potentials(design_str, &mut potentials);
+                println!("potentials: {:?}", potentials);
+                while let Some(curr) = potentials.pop() {
+                    println!("len({})", curr.len());
+                    if curr.len() == 0 {
+                        return true;
+                    }
+                    self.get_potentials(curr, &mut potentials);
+                    //println!("potentials: {:?}", potentials);
+                }
+                false
+            })
+            .count()
+    }
+
+    fn total_valid_designs(self) -> usize {
+        // Ways to make what's left of a design. Shared across designs since they end alike
+        let mut arrangements = Memoized::new(|arrangements, rest: &str| {
+            if rest.is_empty() {
+                return 1;
+            }
+            self.towels
+                .matches_at(rest.as_bytes(), 0)
+                .map(|towel| arrangements.call(&rest[towel.end..]))
+                .sum::<usize>()
+        });
+
+        self.designs
+            .iter()
+            .map(|design_str| arrangements.call(design_str))
+            .sum()
+    }
+
+    fn get_potentials<'a>(&'a self, curr_str: &'a str, potentials: &mut Vec<&'a str>) {
+        for towel in self.towels.matches_at(curr_str.as_bytes(), 0) {
+            let to_push = &curr_str[towel.end..];
+            assert!(to_push.len() < curr_str.len());
+            println!("curr_str: {curr_str}");
+            println!("to_push: {to_push}");
+            potentials.push(to_push);
+        }
+    }
+}
+
+impl Puzzle for Day19 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 19;
+    const TITLE: &'static str = "Linen Layout";
+    const TAGS: &'static [Tag] = &[Tag::Dp, Tag::Parsing];
+    const HELPERS: &'static [&'static str] = &["memo", "trie"];
+    const NOTES: &'static str = "Trie of towels with a memoized count of ways to make what's left \
+        of each design";
+
+    fn puzzle_1(contents: String) -> Answer {
+        let spa = Spa::from(contents);
+        println!("total num designs: {}", spa.designs.len());
+        let num_valid = spa.count_valid_designs();
+        num_valid.into()
+    }
+
+    fn puzzle_2(contents: String) -> Answer {
+        let spa = Spa::from(contents);
+        println!("total num designs: {}", spa.designs.len());
+        let total_valid = spa.total_valid_designs();
+        total_valid.into()
+    }
+}
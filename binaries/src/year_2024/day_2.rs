@@ -1,15 +1,30 @@
-use helpers::Puzzle;
+use std::io::BufRead;
 
-fn main() {
-    Day2::run();
-}
+use helpers::meta::Tag;
+use helpers::{stream, Answer, Puzzle};
 
-struct Day2;
+pub struct Day2;
 
 impl Puzzle for Day2 {
-    fn puzzle_1(contents: String) {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+    const TAGS: &'static [Tag] = &[Tag::Parsing];
+    const HELPERS: &'static [&'static str] = &["stream"];
+    const NOTES: &'static str = "Part 2 only retries without the first level or the levels either \
+        side of where a report first goes wrong, O(n) per report";
+
+    fn puzzle_1(contents: String) -> Answer {
+        Self::stream_1(&mut contents.as_bytes())
+    }
+
+    fn puzzle_2(contents: String) -> Answer {
+        Self::stream_2(&mut contents.as_bytes())
+    }
+
+    fn stream_1(input: &mut dyn BufRead) -> Answer {
         let mut safe_count = 0;
-        for line in contents.lines() {
+        for line in stream::lines(input) {
             let levels = line
                 .split_whitespace()
                 .map(|num| num.parse::<i64>().expect("Has to be a number"));
@@ -18,12 +33,12 @@ impl Puzzle for Day2 {
             }
         }
 
-        println!("Number safe: {safe_count}");
+        safe_count.into()
     }
 
-    fn puzzle_2(contents: String) {
+    fn stream_2(input: &mut dyn BufRead) -> Answer {
         let mut safe_count = 0;
-        for line in contents.lines() {
+        for line in stream::lines(input) {
             let levels: Vec<i64> = line
                 .split_whitespace()
                 .map(|num| num.parse::<i64>().expect("Has to be a number"))
@@ -56,7 +71,7 @@ impl Puzzle for Day2 {
             }
         }
 
-        println!("Number safe: {safe_count}");
+        safe_count.into()
     }
 }
 
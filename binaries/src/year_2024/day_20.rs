@@ -1,8 +1,10 @@
 use std::collections::HashSet;
 
-use helpers::{read_grid, Puzzle};
+use helpers::meta::Tag;
+use helpers::params::{self, Param};
+use helpers::{read_grid, Answer, Puzzle};
 
-struct Day20;
+pub struct Day20;
 
 #[derive(Clone)]
 enum Space {
@@ -180,7 +182,20 @@ fn get_path(grid: &mut Vec<Vec<Space>>, start_coord: (usize, usize)) -> Vec<(usi
 }
 
 impl Puzzle for Day20 {
-    fn puzzle_1(contents: String) {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 20;
+    const TITLE: &'static str = "Race Condition";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Graph];
+    const HELPERS: &'static [&'static str] = &["params", "read_grid"];
+    const NOTES: &'static str = "Distances along the only path, cheats are pairs of path cells \
+        within the cheat length, O(path^2)";
+    // The example has no cheats saving 100, so it's usually run with a much lower `time_to_save`
+    const PARAMS: &'static [Param] = &[
+        Param::int("time_to_save", 100),
+        Param::int("cheat_length", 20),
+    ];
+
+    fn puzzle_1(contents: String) -> Answer {
         let mut start_coord = None;
         let mut grid =
             read_grid(contents)
@@ -213,11 +228,15 @@ impl Puzzle for Day20 {
             println!("skip: {:?}", skip);
         }
         println!("num skips: {}", skips.len());
-        let greater_than_100 = skips.iter().filter(|skip| skip.time_save >= 100).count();
-        println!("found {greater_than_100} skips greater than 100");
+        let time_to_save = params::int("time_to_save");
+        let enough_saved = skips
+            .iter()
+            .filter(|skip| skip.time_save >= time_to_save)
+            .count();
+        enough_saved.into()
     }
 
-    fn puzzle_2(contents: String) {
+    fn puzzle_2(contents: String) -> Answer {
         let mut start_coord = None;
         let mut grid =
             read_grid(contents)
@@ -241,21 +260,19 @@ impl Puzzle for Day20 {
                 });
         let mut possible_skips = HashSet::new();
         let path = get_path(&mut grid, start_coord.expect("has to have a start"));
-        let time_to_save = 100;
+        let time_to_save = params::int("time_to_save");
+        let cheat_length = params::int("cheat_length");
         for range in time_to_save..path.len() {
             for idx in range..path.len() {
                 let start = path[idx - range];
                 let end = path[idx];
                 let distance = manhattan_distance(start, end);
-                if distance <= 20 && range - distance >= time_to_save {
+                if distance <= cheat_length && range - distance >= time_to_save {
                     possible_skips.insert((path[idx - range], path[idx]));
                 }
             }
         }
-        println!(
-            "Found a total of {} skips greater than or equal to 100",
-            possible_skips.len()
-        );
+        possible_skips.len().into()
     }
 }
 
@@ -289,7 +306,3 @@ fn print_grid(grid: Vec<Vec<Space>>, skips: Vec<Skip>) {
     }
     println!("====End====");
 }
-
-fn main() {
-    Day20::run()
-}
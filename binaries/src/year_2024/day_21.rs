@@ -1,8 +1,9 @@
-use std::collections::{hash_map::Entry, HashMap, HashSet};
+use helpers::memo::Memoized;
+use helpers::meta::Tag;
+use helpers::params::{self, Param};
+use helpers::{Answer, Puzzle};
 
-use helpers::Puzzle;
-
-struct Day21;
+pub struct Day21;
 
 struct Coord(isize, isize);
 trait Coords {
@@ -123,7 +124,7 @@ impl TryFrom<char> for Number {
 }
 
 impl Coords for Number {
-    ///
+    /// ```text
     /// +---+---+---+
     /// | 7 | 8 | 9 |
     /// +---+---+---+
@@ -133,6 +134,7 @@ impl Coords for Number {
     /// +---+---+---+
     ///     | 0 | A |
     ///     +---+---+
+    /// ```
     /// With the blank space being (0, 0): (row, col)
     fn get_coord(&self) -> Coord {
         match self {
@@ -156,12 +158,13 @@ impl Coords for Number {
 }
 
 impl Coords for Direction {
-    ///
+    /// ```text
     ///     +---+---+
     ///     | ^ | A |
     /// +---+---+---+
     /// | < | v | > |
     /// +---+---+---+
+    /// ```
     /// where (0, 0) : (row, col) is the < space
     fn get_coord(&self) -> Coord {
         match self {
@@ -260,7 +263,17 @@ fn next_sequence(initial_sequence: Vec<Direction>) -> Vec<Direction> {
 }
 
 impl Puzzle for Day21 {
-    fn puzzle_1(contents: String) {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 21;
+    const TITLE: &'static str = "Keypad Conundrum";
+    const TAGS: &'static [Tag] = &[Tag::Dp];
+    const HELPERS: &'static [&'static str] = &["memo", "params"];
+    const NOTES: &'static str = "Memoized cost of each keypad move per robot layer, so 25 layers \
+        cost about the same as 2";
+    // Depth `calculate_sequence` stops at for part 2
+    const PARAMS: &'static [Param] = &[Param::int("layers", 26)];
+
+    fn puzzle_1(contents: String) -> Answer {
         let final_sum: usize = contents
             .lines()
             .map(|line| {
@@ -289,10 +302,10 @@ impl Puzzle for Day21 {
                 num * third_sequence.len()
             })
             .sum();
-        println!("final sum is: {final_sum}");
+        final_sum.into()
     }
 
-    fn puzzle_2(contents: String) {
+    fn puzzle_2(contents: String) -> Answer {
         let final_sum: usize = contents
             .lines()
             .map(|line| {
@@ -316,40 +329,32 @@ impl Puzzle for Day21 {
                     first_sequence.len(),
                     first_sequence
                 );
-                let mut memoized = HashMap::new();
-                let total_len = calculate_sequence(&mut memoized, first_sequence, 1, 26);
+                let total_len = calculate_sequence(first_sequence, params::int("layers"));
                 println!("total_len is {total_len}");
                 num * total_len
             })
             .sum();
-        println!("final sum is: {final_sum}");
+        final_sum.into()
     }
 }
 
-fn calculate_sequence(
-    memoized: &mut HashMap<MemoizedKey, usize>,
-    sequence: Vec<Direction>,
-    depth: usize,
-    max_depth: usize,
-) -> usize {
-    if depth == max_depth {
-        return sequence.len();
-    }
-    let key = MemoizedKey {
-        sequence: sequence.clone(),
-        depth,
-    };
-    if let Some(memoized_value) = memoized.get(&key) {
-        println!("cache hit!");
-        return *memoized_value;
-    }
-    let next_sequence = next_sequence(sequence);
-    let result = split_on_activate(next_sequence)
-        .into_iter()
-        .map(|curr_sequence| calculate_sequence(memoized, curr_sequence, depth + 1, max_depth))
-        .sum();
-    memoized.insert(key, result);
-    result
+// Length of the button presses `sequence` turns into once expanded down to `max_depth`
+fn calculate_sequence(sequence: Vec<Direction>, max_depth: usize) -> usize {
+    let mut calculate = Memoized::new(|calculate, key: &MemoizedKey| {
+        if key.depth == max_depth {
+            return key.sequence.len();
+        }
+        split_on_activate(next_sequence(key.sequence.clone()))
+            .into_iter()
+            .map(|sequence| {
+                calculate.call(&MemoizedKey {
+                    sequence,
+                    depth: key.depth + 1,
+                })
+            })
+            .sum()
+    });
+    calculate.call(&MemoizedKey { sequence, depth: 1 })
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
@@ -370,7 +375,3 @@ fn split_on_activate(full_sequence: Vec<Direction>) -> Vec<Vec<Direction>> {
     }
     result
 }
-
-fn main() {
-    Day21::run();
-}
@@ -1,8 +1,11 @@
 use std::collections::{HashSet, VecDeque};
+use std::io::BufRead;
 
-use helpers::{HeapNode, IndexedBinaryHeap, Puzzle, UpdateKeyResult};
+use helpers::meta::Tag;
+use helpers::progress::Progress;
+use helpers::{cycle, stream, Answer, HeapNode, IndexedBinaryHeap, Puzzle, UpdateKeyResult};
 
-struct Day22;
+pub struct Day22;
 
 struct FixedSequence {
     queue: VecDeque<isize>,
@@ -34,44 +37,46 @@ impl FixedSequence {
 }
 
 impl Puzzle for Day22 {
-    fn puzzle_1(contents: String) {
-        let total: isize = contents
-            .lines()
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 22;
+    const TITLE: &'static str = "Monkey Market";
+    const TAGS: &'static [Tag] = &[Tag::Simulation, Tag::Math];
+    const HELPERS: &'static [&'static str] = &["cycle", "heap", "progress", "stream"];
+    const NOTES: &'static str = "2000 steps per buyer, part 2 totals the price for every \
+        four-change sequence in a heap";
+
+    fn puzzle_1(contents: String) -> Answer {
+        Self::stream_1(&mut contents.as_bytes())
+    }
+
+    fn puzzle_2(contents: String) -> Answer {
+        Self::stream_2(&mut contents.as_bytes())
+    }
+
+    fn stream_1(input: &mut dyn BufRead) -> Answer {
+        let mut progress = Progress::counting("buyers");
+        let total: isize = stream::lines(input)
             .map(|line_str| {
-                println!("{line_str}");
+                progress.inc();
                 line_str.parse::<isize>().expect("has to be a num")
             })
-            .map(|num| {
-                let mut secret = num;
-                for _ in 0..2000 {
-                    let mult_64 = secret * 64;
-                    secret = mix(secret, mult_64);
-                    secret = prune(secret);
-                    let div_32 = secret / 32;
-                    secret = mix(secret, div_32);
-                    secret = prune(secret);
-                    let mult_2048 = secret * 2048;
-                    secret = mix(secret, mult_2048);
-                    secret = prune(secret);
-                    println!("secret: {secret}");
-                }
-                secret
-            })
+            .map(|num| cycle::iterate(&num, next_secret, 2000))
             .sum();
-        println!("The sum is: {total}");
+        total.into()
     }
 
-    fn puzzle_2(contents: String) {
+    fn stream_2(input: &mut dyn BufRead) -> Answer {
         // TODO TODO TODO: Keep a heap of the current sum you'd get for given sequences
         // update this as you go
         // need to keep in mind that when going through sequences for a given monkey's secret
         // number you will only sell on the first appearance of a sequecne
         let mut heap = IndexedBinaryHeap::<VecDeque<isize>>::max();
-        let test: isize = contents
-            .lines()
+        let mut progress = Progress::counting("buyers");
+        let test: isize = stream::lines(input)
             .enumerate()
             .map(|(idx, line_str)| {
                 //println!("{line_str}");
+                progress.inc();
                 (idx, line_str.parse::<isize>().expect("has to be a num"))
             })
             .map(|(idx, num)| {
@@ -82,16 +87,7 @@ impl Puzzle for Day22 {
                 // Once a sequence is seen it will be sold on first view
                 let mut already_seen = HashSet::new();
                 for _ in 0..2000 {
-                    // Calc new secret
-                    let mult_64 = new_secret * 64;
-                    new_secret = mix(new_secret, mult_64);
-                    new_secret = prune(new_secret);
-                    let div_32 = new_secret / 32;
-                    new_secret = mix(new_secret, div_32);
-                    new_secret = prune(new_secret);
-                    let mult_2048 = new_secret * 2048;
-                    new_secret = mix(new_secret, mult_2048);
-                    new_secret = prune(new_secret);
+                    new_secret = next_secret(&new_secret);
 
                     // Check current sequence
                     let new_sell_value = new_secret % 10;
@@ -126,13 +122,22 @@ impl Puzzle for Day22 {
             .sum();
 
         //heap.print_binary_tree();
-        for i in 1..=10 {
-            println!("top {i} value: {:?}", heap.pop());
-        }
         println!("the sum was: {test}");
+        heap.pop()
+            .expect("has to have seen at least one sequence")
+            .priority()
+            .into()
     }
 }
 
+// Each step is a permutation of the 2^24 secrets and they take far longer than 2000 steps to
+// come back around, so there's no cycle worth skipping. Just step through them
+fn next_secret(secret: &isize) -> isize {
+    let secret = prune(mix(*secret, secret * 64));
+    let secret = prune(mix(secret, secret / 32));
+    prune(mix(secret, secret * 2048))
+}
+
 fn prune(num: isize) -> isize {
     num % 16777216
 }
@@ -140,7 +145,3 @@ fn prune(num: isize) -> isize {
 fn mix(num: isize, mixin: isize) -> isize {
     num ^ mixin
 }
-
-fn main() {
-    Day22::run();
-}
@@ -1,11 +1,42 @@
 use std::collections::{HashMap, HashSet};
 
-use helpers::Puzzle;
+use helpers::dot::{self, Graph};
+use helpers::meta::Tag;
+use helpers::params::Param;
+use helpers::{Answer, Puzzle};
 
-struct Day23;
+pub struct Day23;
+
+// The whole network with the computers in `clique` and the links between them picked out
+fn lan_graph(graph: &HashMap<&str, HashSet<&str>>, clique: &[&&str]) -> Graph {
+    let in_clique = |node: &str| clique.iter().any(|member| **member == node);
+    let mut dot_graph = Graph::undirected("lan");
+    for (node, connected) in graph {
+        if in_clique(node) {
+            dot_graph.node(node).fill("tomato");
+        }
+        // Every link is stored from both ends, only draw it once
+        for other in connected.iter().filter(|other| node < *other) {
+            let edge = dot_graph.edge(node, other);
+            if in_clique(node) && in_clique(other) {
+                edge.color("red");
+            }
+        }
+    }
+    dot_graph
+}
 
 impl Puzzle for Day23 {
-    fn puzzle_1(contents: String) {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 23;
+    const TITLE: &'static str = "LAN Party";
+    const TAGS: &'static [Tag] = &[Tag::Graph];
+    const HELPERS: &'static [&'static str] = &["dot", "params"];
+    const NOTES: &'static str = "Triangles found as edges are added, part 2 greedily grows a \
+        clique from every computer";
+    const PARAMS: &'static [Param] = &[Param::bool(dot::PARAM, false)];
+
+    fn puzzle_1(contents: String) -> Answer {
         let (graph, groups) = contents
             .lines()
             .map(|line| line.split_once("-").expect("has to have -"))
@@ -52,10 +83,10 @@ impl Puzzle for Day23 {
                 }
             }
         }
-        println!("number of t groups is {}", t_groups.len());
+        t_groups.len().into()
     }
 
-    fn puzzle_2(contents: String) {
+    fn puzzle_2(contents: String) -> Answer {
         let graph = contents
             .lines()
             .map(|line| line.split_once("-").expect("has to have -"))
@@ -105,14 +136,12 @@ impl Puzzle for Day23 {
             maximum_group.len(),
             maximum_group
         );
-        print!("password is: ");
-        for val in maximum_group {
-            print!("{},", val);
-        }
-        println!("");
+        dot::emit_if_requested("2024-day-23", || lan_graph(&graph, &maximum_group));
+        maximum_group
+            .into_iter()
+            .map(|val| val.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+            .into()
     }
 }
-
-fn main() {
-    Day23::run();
-}
@@ -1,12 +1,12 @@
-use std::{
-    cell::RefCell,
-    collections::{HashMap, HashSet, VecDeque},
-    rc::Rc,
-};
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
 
-use helpers::Puzzle;
+use helpers::dot::{self, Graph};
+use helpers::hash::{FastMap, FastSet};
+use helpers::meta::Tag;
+use helpers::params::Param;
+use helpers::{Answer, BitVec, Puzzle};
 
-struct Day24;
+pub struct Day24;
 
 #[derive(Debug)]
 struct Wire {
@@ -16,7 +16,7 @@ struct Wire {
 }
 
 impl Wire {
-    fn deep_clone(&self, cloned_gates: &mut HashMap<String, Rc<RefCell<Gate>>>) -> Self {
+    fn deep_clone(&self, cloned_gates: &mut FastMap<String, Rc<RefCell<Gate>>>) -> Self {
         Self {
             name: self.name.clone(),
             feeds_into: self
@@ -109,7 +109,7 @@ enum GateKind {
 
 #[derive(Debug)]
 struct Circuit {
-    wires: HashMap<String, Wire>,
+    wires: FastMap<String, Wire>,
     gates: Vec<Rc<RefCell<Gate>>>,
 }
 
@@ -158,12 +158,12 @@ impl Circuit {
 
 impl Clone for Circuit {
     fn clone(&self) -> Self {
-        let mut cloned_gates = HashMap::new();
+        let mut cloned_gates = FastMap::default();
         let wires = self
             .wires
             .iter()
             .map(|(key, val)| (key.clone(), val.deep_clone(&mut cloned_gates)))
-            .collect::<HashMap<String, Wire>>();
+            .collect::<FastMap<String, Wire>>();
         let gates = cloned_gates.into_values().collect::<Vec<_>>();
         assert!(gates.len() == self.gates.len());
         Self { wires, gates }
@@ -213,7 +213,7 @@ fn build_circuit(contents: String) -> (VecDeque<(String, u8)>, Circuit) {
     let (wires, gates) = line_iter
         .map(|curr_line| curr_line.split_once(" -> ").expect("has to have \" -> \""))
         .fold(
-            (HashMap::new(), Vec::new()),
+            (FastMap::default(), Vec::new()),
             |(mut wires, mut gates), (input_def, output_def)| {
                 // Input parsing
                 let input_split = input_def.split_whitespace().collect::<Vec<_>>();
@@ -271,13 +271,13 @@ fn build_circuit(contents: String) -> (VecDeque<(String, u8)>, Circuit) {
 }
 
 struct RunResult {
-    values: HashMap<String, u8>,
+    values: FastMap<String, u8>,
 }
 
 fn run_circuit(starting_values: VecDeque<(String, u8)>, circuit: &Circuit) -> RunResult {
     let wires = &circuit.wires;
     let mut to_process = starting_values;
-    let mut values = HashMap::new();
+    let mut values = FastMap::default();
     while let Some((name, byte)) = to_process.pop_front() {
         values.insert(name.to_string(), byte);
         let wire = wires
@@ -292,8 +292,71 @@ fn run_circuit(starting_values: VecDeque<(String, u8)>, circuit: &Circuit) -> Ru
     RunResult { values }
 }
 
+// Wires worth a look: outputs that came out with the wrong bit, and wires carrying a signal between
+// two kinds of gate that never feed each other in a ripple carry adder
+fn suspect_wires(
+    circuit: &Circuit,
+    wrong_outputs: impl Iterator<Item = String>,
+) -> FastSet<String> {
+    let mut suspects = wrong_outputs.collect::<FastSet<_>>();
+    for gate_ref in &circuit.gates {
+        let gate = gate_ref.borrow();
+        let ComputeState::Both(first, second) = &gate.state else {
+            continue;
+        };
+        for input in [first, second] {
+            let feeding = circuit.wires[&input.wire_name].fed_from.as_ref();
+            if feeding.is_some_and(|feeding| bad_transition(&feeding.borrow(), &gate)) {
+                suspects.insert(input.wire_name.clone());
+            }
+        }
+    }
+    suspects
+}
+
+// Wires and gates as nodes, with the x and y inputs and z outputs boxed up on their own. Needs a
+// circuit that's been run since that's when gates learn which wires feed them
+fn circuit_graph(circuit: &Circuit, suspects: &FastSet<String>) -> Graph {
+    let mut graph = Graph::directed("netlist");
+    for name in circuit.wires.keys() {
+        let node = graph.node(name);
+        match name.chars().next() {
+            Some('x' | 'y') => node.cluster("inputs"),
+            Some('z') => node.cluster("outputs"),
+            _ => node,
+        };
+        if suspects.contains(name) {
+            node.fill("tomato");
+        }
+    }
+    for gate_ref in &circuit.gates {
+        let gate = gate_ref.borrow();
+        let ComputeState::Both(first, second) = &gate.state else {
+            continue;
+        };
+        let gate_id = format!("gate {}", gate.output_wire_name);
+        graph
+            .node(&gate_id)
+            .label(format!("{:?}", gate.kind))
+            .shape("box");
+        graph.edge(&first.wire_name, &gate_id);
+        graph.edge(&second.wire_name, &gate_id);
+        graph.edge(&gate_id, &gate.output_wire_name);
+    }
+    graph
+}
+
 impl Puzzle for Day24 {
-    fn puzzle_1(contents: String) {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 24;
+    const TITLE: &'static str = "Crossed Wires";
+    const TAGS: &'static [Tag] = &[Tag::Graph, Tag::Simulation];
+    const HELPERS: &'static [&'static str] = &["bit_vec", "dot", "hash", "params"];
+    const NOTES: &'static str = "Simulates the gates, part 2 applies swaps found by hand so only \
+        works on one input";
+    const PARAMS: &'static [Param] = &[Param::bool(dot::PARAM, false)];
+
+    fn puzzle_1(contents: String) -> Answer {
         let (starting_values, final_circuit) = build_circuit(contents);
 
         let run_result = run_circuit(starting_values, &final_circuit);
@@ -301,11 +364,22 @@ impl Puzzle for Day24 {
 
         let (x, _) = convert_to_usize('x', &result_values);
         let (y, _) = convert_to_usize('y', &result_values);
-        let (num, _) = convert_to_usize('z', &result_values);
+        let (num, num_bit_vec) = convert_to_usize('z', &result_values);
         println!("x + y = z: {x} + {y} = {num}");
+        dot::emit_if_requested("2024-day-24", || {
+            // The circuit is meant to add, so any bit that doesn't is a place to start looking
+            let expected_bit_vec = BitVec::from_int(x + y, num_bit_vec.len());
+            let diff = expected_bit_vec.diff(&num_bit_vec);
+            let wrong_outputs = diff.mismatches().map(|idx| format!("z{idx:0>2}"));
+            circuit_graph(
+                &final_circuit,
+                &suspect_wires(&final_circuit, wrong_outputs),
+            )
+        });
+        num.into()
     }
 
-    fn puzzle_2(contents: String) {
+    fn puzzle_2(contents: String) -> Answer {
         let (starting_points, initial_circuit) = build_circuit(contents);
 
         let mut first_circuit = initial_circuit.clone();
@@ -357,16 +431,11 @@ impl Puzzle for Day24 {
 
         let expected_num = x + y;
         println!("expected_num: {expected_num}, num: {num}");
-        let expected_bit_vec = convert_to_bits(expected_num, num_bit_vec.len());
+        let expected_bit_vec = BitVec::from_int(expected_num, num_bit_vec.len());
 
         println!("should be x + y = z: {x} + {y} = {}", x + y);
         println!("is actually x + y = z: {x} + {y} = {num}");
-
-        let (idx_string, expected_string, actual_string, _wrong_output_wires) =
-            diff_bit_vecs(expected_bit_vec.clone(), num_bit_vec.into());
-        println!("{idx_string}");
-        println!("{expected_string}");
-        println!("{actual_string}");
+        println!("{}", expected_bit_vec.diff(&num_bit_vec));
 
         let circuit_paths = get_circuit_paths(
             vec!["z03".to_string()],
@@ -428,16 +497,21 @@ impl Puzzle for Day24 {
         //         );
         //     }
         // }
+
+        final_str.into()
     }
 }
 
 fn convert_num_to_starting_points(leading_char: char, num: usize, len: usize) -> Vec<(String, u8)> {
-    let bit_vec = convert_to_bits(num, len);
-    //println!("{:?}", x_bit_vec);
-    bit_vec
-        .into_iter()
+    BitVec::from_int(num, len)
+        .iter_msb()
         .enumerate()
-        .map(|(idx, bit_val)| (format!("{leading_char}{:0>2}", len - idx - 1), bit_val))
+        .map(|(idx, bit_val)| {
+            (
+                format!("{leading_char}{:0>2}", len - idx - 1),
+                bit_val as u8,
+            )
+        })
         .collect::<Vec<_>>()
 }
 
@@ -461,18 +535,19 @@ fn compare(
     assert!(x == x_num);
     assert!(y == y_num);
     let (num, num_bit_vec) = convert_to_usize('z', &run_result.values);
-    let expected_bit_vec = convert_to_bits(expected_num, num_bit_vec.len());
+    let expected_bit_vec = BitVec::from_int(expected_num, num_bit_vec.len());
     println!("(actual) {num} == (expected) {expected_num}");
     if expected_num != num {
         println!("x : expected {x_num} actual {x}");
-        println!("{:?}", x_num_bit_vec);
+        println!("{x_num_bit_vec}");
         println!("y : expected {y_num} actual {y}");
-        println!("{:?}", y_num_bit_vec);
-        let (idx_string, expected_string, actual_string, wrong_output_wires) =
-            diff_bit_vecs(expected_bit_vec.clone(), num_bit_vec.into());
-        println!("{idx_string}");
-        println!("{expected_string}");
-        println!("{actual_string}");
+        println!("{y_num_bit_vec}");
+        let diff = expected_bit_vec.diff(&num_bit_vec);
+        println!("{diff}");
+        let wrong_output_wires = diff
+            .mismatches()
+            .map(|idx| format!("z{idx:0>2}"))
+            .collect::<Vec<_>>();
 
         // Added the z38 here because it was helpful for debugging this final miss
         let mut to_check = wrong_output_wires[1..2].to_vec();
@@ -502,7 +577,7 @@ fn compare(
 }
 
 fn get_circuit_paths(wrong_output_wires: Vec<String>, circuit: &Circuit) -> CircuitPaths {
-    let mut bad_gates = HashSet::new();
+    let mut bad_gates = FastSet::default();
 
     let paths = wrong_output_wires
         .into_iter()
@@ -641,92 +716,14 @@ fn print_gate(gate: &Gate) {
     }
 }
 
-fn diff_bit_vecs(
-    expected_bit_vec: Vec<u8>,
-    actual_bit_vec: Vec<u8>,
-) -> (String, String, String, Vec<String>) {
-    let mut idx_string = String::with_capacity(expected_bit_vec.len() * 2);
-    let mut expected_string = String::with_capacity(expected_bit_vec.len() * 2);
-    let mut actual_string = String::with_capacity(expected_bit_vec.len() * 2);
-    let mut wrong_output_wires = Vec::new();
-    for (idx, (expected_bit, actual_bit)) in expected_bit_vec
-        .iter()
-        .zip(actual_bit_vec.iter())
-        .enumerate()
-    {
-        if expected_bit != actual_bit {
-            let wrong_wire_name = format!("z{:0>2}", actual_bit_vec.len() - idx);
-            println!("the bit at {idx} ({wrong_wire_name}) doesn't match",);
-            wrong_output_wires.push(wrong_wire_name);
-        }
-        idx_string += format!("{: >2}", idx).as_str();
-        expected_string += format!("{: >2}", expected_bit).as_str();
-        actual_string += format!("{: >2}", actual_bit).as_str();
-    }
-    (
-        idx_string,
-        expected_string,
-        actual_string,
-        wrong_output_wires,
-    )
-}
-
-// copied from day-17
-// Claude basically wrote this function
-fn convert_to_bits(num: usize, len: usize) -> Vec<u8> {
-    // Get number of bits needed to represent the number
-    let bits_needed = if num == 0 {
-        return vec![0; len];
-    } else {
-        (usize::BITS - num.leading_zeros()) as usize
-    };
-
-    let mut bits = Vec::with_capacity(bits_needed);
-    let mut n = num;
-
-    // Extract bits from right to left
-    while n > 0 {
-        let bit = match n & 1 {
-            0 => 0,
-            1 => 1,
-            _ => panic!("this is impossible"),
-        };
-        bits.push(bit);
-        n >>= 1;
-    }
-
-    // If number was 0, push a single 0
-    if bits.is_empty() {
-        bits.push(0);
-    }
-
-    // Include leading zeros up to length
-    while bits.len() < len {
-        bits.push(0);
-    }
-    // Reverse to get most significant bits first
-    bits.reverse();
-    //println!("bits.len(): {}, len: {len}", bits.len());
-    assert!(bits.len() == len);
-    bits
-}
-
-fn convert_to_usize(leading_char: char, result: &HashMap<String, u8>) -> (usize, VecDeque<u8>) {
-    let mut keys = result.keys().collect::<Vec<_>>();
+// Reads the wires starting with `leading_char` as a number, `x00` being the lowest bit
+fn convert_to_usize(leading_char: char, result: &FastMap<String, u8>) -> (usize, BitVec) {
+    let mut keys = result
+        .keys()
+        .filter(|key| key.starts_with(leading_char))
+        .collect::<Vec<_>>();
     keys.sort();
-    let mut bit_vec = VecDeque::new();
-    let mut num = 0;
-    for key in keys {
-        if key.starts_with(leading_char) {
-            let byte = result.get(key).expect("has to exist");
-            num +=
-                *byte as usize * 2_usize.pow(bit_vec.len().try_into().expect("has to fit in u32"));
-            bit_vec.push_front(*result.get(key).expect("has to exist"));
-        }
-    }
+    let bit_vec = BitVec::from_lsb(keys.into_iter().map(|key| result[key] == 1));
+    let num = bit_vec.to_int().expect("has to fit in a usize");
     (num, bit_vec)
 }
-
-fn main() {
-    Day24::run()
-}
@@ -1,6 +1,7 @@
-use helpers::Puzzle;
+use helpers::meta::Tag;
+use helpers::{Answer, Puzzle};
 
-struct Day25;
+pub struct Day25;
 
 #[derive(Debug)]
 struct Counters {
@@ -29,7 +30,14 @@ impl Counters {
 }
 
 impl Puzzle for Day25 {
-    fn puzzle_1(contents: String) {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 25;
+    const TITLE: &'static str = "Code Chronicle";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Parsing];
+    const NOTES: &'static str = "Column heights for every schematic, every key checked against \
+        every lock";
+
+    fn puzzle_1(contents: String) -> Answer {
         let mut iter = contents.lines();
         let mut keys = Vec::new();
         let mut locks = Vec::new();
@@ -74,14 +82,10 @@ impl Puzzle for Day25 {
             .map(|lock| keys.iter().filter(|key| Counters::fits(key, lock)).count())
             .sum();
 
-        println!("The number of matches is {sum}");
+        sum.into()
     }
 
-    fn puzzle_2(contents: String) {
+    fn puzzle_2(contents: String) -> Answer {
         todo!()
     }
 }
-
-fn main() {
-    Day25::run()
-}
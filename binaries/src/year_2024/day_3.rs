@@ -0,0 +1,107 @@
+use std::io::BufRead;
+
+use helpers::meta::Tag;
+use helpers::{stream, Answer, Puzzle};
+use regex::bytes::Regex;
+
+pub struct Day3;
+
+// The longest thing either regex can match, mul(123,456)
+const LONGEST_MATCH: usize = 12;
+
+fn split_mult(match_val: &str) -> (&str, &str) {
+    match_val
+        .strip_prefix("mul(")
+        .expect("Had to start with mul(")
+        .strip_suffix(")")
+        .expect("Had to end with )")
+        .split_once(",")
+        .expect("Had to have one comma")
+}
+
+// Every match of `regex` in the input, read a chunk at a time
+fn for_each_match(input: &mut dyn BufRead, regex: &Regex, mut on_match: impl FnMut(&str)) {
+    stream::find_all(
+        input,
+        LONGEST_MATCH,
+        |haystack, from| regex.find_at(haystack, from).map(|found| found.range()),
+        |found| on_match(std::str::from_utf8(found).expect("Matches are all ascii")),
+    );
+}
+
+impl Puzzle for Day3 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Mull It Over";
+    const TAGS: &'static [Tag] = &[Tag::Parsing];
+    const HELPERS: &'static [&'static str] = &["stream"];
+    const NOTES: &'static str = "Regex scan a chunk at a time, tracking do() and don't() as it \
+        goes, O(n)";
+
+    fn puzzle_1(contents: String) -> Answer {
+        Self::stream_1(&mut contents.as_bytes())
+    }
+
+    fn puzzle_2(contents: String) -> Answer {
+        Self::stream_2(&mut contents.as_bytes())
+    }
+
+    fn stream_1(input: &mut dyn BufRead) -> Answer {
+        let regex = Regex::new(r"mul\(\d{1,3},\d{1,3}\)").expect("Should be valid regex");
+
+        let mut result = 0;
+        for_each_match(input, &regex, |mult_str| {
+            let (first_str, second_str) = split_mult(mult_str);
+            let first_num = first_str.parse::<i64>().expect("Has to be a num");
+            let second_num = second_str.parse::<i64>().expect("Has to be a num");
+            result += first_num * second_num;
+        });
+
+        result.into()
+    }
+
+    fn stream_2(input: &mut dyn BufRead) -> Answer {
+        let regex =
+            Regex::new(r"mul\(\d{1,3},\d{1,3}\)|do\(\)|don't\(\)").expect("Should be valid regex");
+
+        let mut should_mult = true;
+        let mut product = 0;
+
+        for_each_match(input, &regex, |match_val| {
+            let mult_val = match match_val {
+                "do()" => MultType::Do,
+                "don't()" => MultType::Dont,
+                _ => MultType::from(split_mult(match_val)),
+            };
+            match mult_val {
+                MultType::Do => {
+                    should_mult = true;
+                }
+                MultType::Dont => {
+                    should_mult = false;
+                }
+                MultType::Mult(val) => {
+                    if should_mult {
+                        product += val
+                    }
+                }
+            }
+        });
+
+        product.into()
+    }
+}
+
+enum MultType {
+    Do,
+    Dont,
+    Mult(i64),
+}
+
+impl From<(&str, &str)> for MultType {
+    fn from(value: (&str, &str)) -> Self {
+        let first_num = value.0.parse::<i64>().expect("Has to be a num");
+        let second_num = value.1.parse::<i64>().expect("Has to be a num");
+        MultType::Mult(first_num * second_num)
+    }
+}
@@ -0,0 +1,97 @@
+use helpers::meta::Tag;
+use helpers::{read_grid, AhoCorasick, Answer, Puzzle, Trie};
+
+pub struct Day4;
+
+// Every straight line through the grid: rows, columns, then both sets of diagonals. Searching
+// each one for the word forwards and backwards covers all 8 directions
+fn grid_lines(grid: &[Vec<char>]) -> Vec<Vec<char>> {
+    let height = grid.len();
+    let width = grid.first().map_or(0, |row| row.len());
+    let mut lines = grid.to_vec();
+    if width == 0 {
+        return lines;
+    }
+    lines.extend((0..width).map(|c| (0..height).map(|r| grid[r][c]).collect()));
+    // Each diagonal starts somewhere along the top row or down the side
+    for start in 0..width + height - 1 {
+        let (r, c) = (
+            start.saturating_sub(width - 1),
+            (width - 1).saturating_sub(start),
+        );
+        lines.push((r..height).zip(c..width).map(|(r, c)| grid[r][c]).collect());
+        let (r, c) = (start.saturating_sub(width - 1), start.min(width - 1));
+        lines.push(
+            (r..height)
+                .zip((0..=c).rev())
+                .map(|(r, c)| grid[r][c])
+                .collect(),
+        );
+    }
+    lines
+}
+
+impl Puzzle for Day4 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Ceres Search";
+    const TAGS: &'static [Tag] = &[Tag::Grid];
+    const HELPERS: &'static [&'static str] = &["read_grid", "trie"];
+    const NOTES: &'static str = "Aho-Corasick over every row, column and diagonal, part 2 checks \
+        the corners around each A";
+
+    fn puzzle_1(contents: String) -> Answer {
+        let grid = read_grid(contents);
+        let mut words = Trie::new();
+        words.insert("XMAS".chars());
+        words.insert("SAMX".chars());
+        let words = AhoCorasick::new(words);
+        let xmas_count = grid_lines(&grid)
+            .into_iter()
+            .map(|line| words.find_iter(line).count())
+            .sum::<usize>();
+
+        xmas_count.into()
+    }
+
+    fn puzzle_2(contents: String) -> Answer {
+        let grid = read_grid(contents);
+        let mut xmas_count = 0;
+        for r in 0..grid.len() {
+            for c in 0..grid[r].len() {
+                if grid[r][c] == 'A' {
+                    if r >= 1 && c >= 1 && r + 1 < grid.len() && c + 1 < grid[r].len() {
+                        // NOTE: Almost certainly overcomplicated it. I assumed they didn't have to
+                        // be diagonal across (which I think is still unclear from the rules) and
+                        // could be like the following. Probalby an easier way to just check
+                        // diagonals
+                        // M.S
+                        // .A.
+                        // S.M
+                        let corners_chars = [
+                            grid[r - 1][c - 1],
+                            grid[r + 1][c - 1],
+                            grid[r - 1][c + 1],
+                            grid[r + 1][c + 1],
+                        ];
+                        let (s_count, m_count) =
+                            corners_chars
+                                .iter()
+                                .fold((0, 0), |(s_count, m_count), char_val| match char_val {
+                                    'M' => (s_count, m_count + 1),
+                                    'S' => (s_count + 1, m_count),
+                                    _ => (s_count, m_count),
+                                });
+
+                        if s_count == 2 && m_count == 2 && grid[r - 1][c - 1] != grid[r + 1][c + 1]
+                        {
+                            xmas_count += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        xmas_count.into()
+    }
+}
@@ -5,13 +5,12 @@ use std::{
     str::Lines,
 };
 
-use helpers::Puzzle;
+use helpers::dot::{self, Graph};
+use helpers::meta::Tag;
+use helpers::params::Param;
+use helpers::{Answer, Puzzle};
 
-fn main() {
-    Day5::run();
-}
-
-struct Day5;
+pub struct Day5;
 
 #[derive(Debug)]
 struct Dag {
@@ -108,13 +107,39 @@ fn generate_dag(line_iter: &mut Lines<'_>) -> Dag {
     }
 }
 
+// One node per page with an edge for every rule, pointing from the page that has to come first.
+// Pages that no rule says has to come after anything are filled in
+fn dag_graph(dag: &Dag) -> Graph {
+    let mut graph = Graph::directed("ordering rules");
+    for root in &dag.roots {
+        graph.node(root).fill("lightblue");
+    }
+    for (before, afters) in &dag.graph {
+        for after in afters {
+            graph.edge(before, after);
+        }
+    }
+    graph
+}
+
 impl Puzzle for Day5 {
-    fn puzzle_1(contents: String) {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Print Queue";
+    const TAGS: &'static [Tag] = &[Tag::Graph, Tag::Parsing];
+    const HELPERS: &'static [&'static str] = &["dot", "params"];
+    const NOTES: &'static str =
+        "Checks each update against the rule DAG and reorders the bad ones \
+        with a topological sort";
+    const PARAMS: &'static [Param] = &[Param::bool(dot::PARAM, false)];
+
+    fn puzzle_1(contents: String) -> Answer {
         let mut line_iter = contents.lines();
 
         let dag = generate_dag(&mut line_iter);
 
         println!("The dag is: {:?}", dag);
+        dot::emit_if_requested("2024-day-5", || dag_graph(&dag));
 
         let result = line_iter
             .map(|line| line.split(",").collect::<Vec<_>>())
@@ -125,10 +150,10 @@ impl Puzzle for Day5 {
                     .expect("has to be a number")
             });
 
-        println!("Sum of sorted middles is: {result}");
+        result.into()
     }
 
-    fn puzzle_2(contents: String) {
+    fn puzzle_2(contents: String) -> Answer {
         let mut line_iter = contents.lines();
 
         let dag = generate_dag(&mut line_iter);
@@ -149,7 +174,7 @@ impl Puzzle for Day5 {
                     .expect("has to be a number")
             });
 
-        println!("Sum of sorted middles is: {result}");
+        result.into()
     }
 }
 
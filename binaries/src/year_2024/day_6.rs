@@ -1,12 +1,10 @@
-use std::{cell::RefCell, rc::Rc};
+use helpers::hash::FastSet;
+use helpers::meta::Tag;
+use helpers::progress::Progress;
+use helpers::sim::{Driver, Simulation};
+use helpers::{read_grid, Answer, Puzzle};
 
-use helpers::{read_grid, Puzzle};
-
-fn main() {
-    Day6::run();
-}
-
-struct Day6;
+pub struct Day6;
 
 #[derive(PartialEq, Eq, Clone, Debug)]
 enum Direction {
@@ -43,24 +41,6 @@ impl Direction {
         false
     }
 
-    fn matches(&self, space: &Space) -> Option<SpaceInfo> {
-        match space {
-            Space::Visited(space_info) => {
-                if space_info.travel_direction == *self {
-                    Some(space_info.clone())
-                } else {
-                    None
-                }
-            }
-            Space::Intersection(space_infos) => space_infos
-                .iter()
-                .find(|space_info| space_info.travel_direction == *self)
-                .map(|space_info| space_info.clone()),
-            Space::Empty => None,
-            Space::Obstacle => panic!("Can't compare a direction with an obstacle space"),
-        }
-    }
-
     fn turn(self) -> Self {
         match self {
             Direction::Up => Direction::Right,
@@ -115,6 +95,35 @@ impl Direction {
     }
 }
 
+// The guard walking the lab, `None` once they've walked off the grid
+struct Patrol<'a> {
+    grid: &'a Vec<Vec<Space>>,
+}
+
+type Guard = Option<(usize, usize, Direction)>;
+
+impl Simulation for Patrol<'_> {
+    type State = Guard;
+
+    fn step(&self, guard: &mut Guard) {
+        let Some((row, col, direction)) = guard.take() else {
+            return;
+        };
+        *guard = if direction.is_exiting(self.grid, &row, &col) {
+            None
+        } else if direction.should_turn(self.grid, &row, &col) {
+            Some((row, col, direction.turn()))
+        } else {
+            let (row, col) = direction.peek_update_pos(&row, &col);
+            Some((row, col, direction))
+        };
+    }
+
+    fn is_done(&self, guard: &Guard) -> bool {
+        guard.is_none()
+    }
+}
+
 fn traverse_grid(
     start_pos: (usize, usize),
     start_direction: Direction,
@@ -181,12 +190,29 @@ fn mark_path_to_exit(
 }
 
 impl Puzzle for Day6 {
-    fn puzzle_1(contents: String) {
-        let (mut space_grid, start_pos) = build_space_grid(contents);
-
-        let (spaces_covered, _, _) = mark_path_to_exit(start_pos, &mut space_grid);
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Simulation];
+    const HELPERS: &'static [&'static str] = &["hash", "progress", "read_grid", "sim"];
+    const NOTES: &'static str = "Walks the path, part 2 tries an obstacle after every step and \
+        finds cycles with Brent's algorithm, O(path^2)";
+
+    fn puzzle_1(contents: String) -> Answer {
+        let (space_grid, start_pos) = build_space_grid(contents);
+
+        let mut visited = FastSet::default();
+        visited.insert(start_pos);
+        let patrol = Patrol { grid: &space_grid };
+        Driver::new(patrol, Some((start_pos.0, start_pos.1, Direction::Up)))
+            .on_step(|_, guard| {
+                if let Some((row, col, _)) = guard {
+                    visited.insert((*row, *col));
+                }
+            })
+            .run_to_end();
 
-        println!("Number of spaces covered: {spaces_covered}");
+        visited.len().into()
     }
 
     /**
@@ -201,15 +227,18 @@ impl Puzzle for Day6 {
      * 4. Do this till you exit and then return the count
      */
 
-    fn puzzle_2(contents: String) {
+    fn puzzle_2(contents: String) -> Answer {
         let (mut grid, start_pos) = build_space_grid(contents);
 
-        let (_, final_count, grid) = mark_path_to_exit(start_pos, &mut grid);
+        let (_, path_len, grid) = mark_path_to_exit(start_pos, &mut grid);
 
         let mut loops_found = 0;
+        // The second lap visits every position on the path once, trying an obstacle after each
+        let mut progress = Progress::new("obstacles tried", path_len as usize);
         let find_loops_fn = |grid: &mut Vec<Vec<Space>>,
                              starting_coords: (usize, usize),
                              curr_direction: Direction| {
+            progress.inc();
             let grid = &mut grid.clone();
             let (starting_row, starting_col) = starting_coords;
 
@@ -249,62 +278,16 @@ impl Puzzle for Day6 {
             let new_obstacle_space = &mut grid[peek.0][peek.1];
             *new_obstacle_space = Space::Obstacle;
 
-            let new_spaces_count = Rc::new(RefCell::new(final_count));
-            let find_loop_should_continue_fn =
-                |grid: &Vec<Vec<Space>>, coords: (&usize, &usize), direction: &Direction| {
-                    let is_exiting = direction.is_exiting(grid, coords.0, coords.1);
-
-                    // Fist square will always match but we don't care about this, quit
-                    if *new_spaces_count.borrow() == final_count && !is_exiting {
-                        return true;
-                    }
-                    if let Some(space_info) = direction.matches(&grid[*coords.0][*coords.1]) {
-                        if space_info.count < starting_count || space_info.count > final_count {
-                            loops_found += 1;
-                            return false;
-                        }
-                    }
-                    !is_exiting
-                };
-
-            let find_loop_update_fn =
-                |grid: &mut Vec<Vec<Space>>,
-                 inner_coords: (usize, usize),
-                 inner_direction: Direction| {
-                    let (row, col) = inner_coords;
-
-                    // TODO: Determine if its best practice to underscore this like this
-                    *new_spaces_count.borrow_mut() += 1;
-                    let space = &mut grid[row][col];
-                    let new_space_info = SpaceInfo {
-                        travel_direction: inner_direction,
-                        count: *new_spaces_count.borrow(),
-                    };
-                    match space {
-                        Space::Visited(space_info) => {
-                            let space_infos = vec![space_info.clone(), new_space_info];
-                            *space = Space::Intersection(space_infos);
-                        }
-                        Space::Intersection(space_infos) => {
-                            space_infos.push(new_space_info);
-                        }
-                        Space::Empty => {
-                            *space = Space::Visited(new_space_info);
-                        }
-                        Space::Obstacle => panic!("Shouldn't be able to be 'on' an obstacle here"),
-                    };
-                };
-            traverse_grid(
-                starting_coords,
-                curr_direction,
-                grid,
-                find_loop_should_continue_fn,
-                find_loop_update_fn,
-            );
+            // Leaving the grid ends up stuck on `None`, a cycle of 1. The guard always turns or
+            // moves so any loop on the grid has to be longer than that
+            let guard = Some((starting_row, starting_col, curr_direction));
+            if Driver::new(Patrol { grid }, guard).find_cycle().length > 1 {
+                loops_found += 1;
+            }
         };
         traverse_grid(start_pos, Direction::Up, grid, is_exiting, find_loops_fn);
 
-        println!("number of ways to create a loop: {loops_found}");
+        loops_found.into()
     }
 }
 
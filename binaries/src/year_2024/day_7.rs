@@ -1,15 +1,30 @@
-use helpers::Puzzle;
+use std::io::BufRead;
 
-fn main() {
-    Day7::run()
-}
+use helpers::meta::Tag;
+use helpers::{stream, Answer, Puzzle};
 
-struct Day7;
+pub struct Day7;
 
 impl Puzzle for Day7 {
-    fn puzzle_1(contents: String) {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+    const TAGS: &'static [Tag] = &[Tag::Parsing];
+    const HELPERS: &'static [&'static str] = &["stream"];
+    const NOTES: &'static str = "Tries every operator combination for each equation, exponential \
+        in how many values it has";
+
+    fn puzzle_1(contents: String) -> Answer {
+        Self::stream_1(&mut contents.as_bytes())
+    }
+
+    fn puzzle_2(contents: String) -> Answer {
+        Self::stream_2(&mut contents.as_bytes())
+    }
+
+    fn stream_1(input: &mut dyn BufRead) -> Answer {
         let mut sum = 0;
-        for line in contents.lines() {
+        for line in stream::lines(input) {
             let (target_val_str, rest_of_line) = line.split_once(":").expect("Has to have a :");
             let target_val = target_val_str
                 .parse::<i64>()
@@ -51,12 +66,12 @@ impl Puzzle for Day7 {
             }
         }
 
-        println!("Sum is: {sum}");
+        sum.into()
     }
 
-    fn puzzle_2(contents: String) {
+    fn stream_2(input: &mut dyn BufRead) -> Answer {
         let mut sum = 0;
-        for line in contents.lines() {
+        for line in stream::lines(input) {
             let (target_val_str, rest_of_line) = line.split_once(":").expect("Has to have a :");
             let target_val = target_val_str
                 .parse::<i64>()
@@ -111,7 +126,7 @@ impl Puzzle for Day7 {
             }
         }
 
-        println!("Sum is: {sum}");
+        sum.into()
     }
 }
 
@@ -1,12 +1,9 @@
 use std::{collections::HashMap, path::Ancestors};
 
-use helpers::{read_grid, Puzzle};
+use helpers::meta::Tag;
+use helpers::{num, read_grid, Answer, Puzzle};
 
-fn main() {
-    Day8::run();
-}
-
-struct Day8;
+pub struct Day8;
 
 type Coords = (usize, usize);
 
@@ -74,72 +71,31 @@ impl NodePair {
         result
     }
 
+    // Every grid point on the line through both antennas counts, including any between them, so
+    // step by the difference cut down by its gcd and walk both ways until leaving the grid
     fn find_limitless_antinodes(
         self,
         row_max: usize,
         col_max: usize,
     ) -> Vec<(Coords, Coords, Coords)> {
-        let mut result = Vec::with_capacity(4);
-        match self {
-            NodePair::TopLeftBottomRight(top_left, bottom_right) => {
-                let row_diff = bottom_right.0 - top_left.0;
-                let col_diff = bottom_right.1 - top_left.1;
-
-                let mut top_left = top_left;
-                let mut bottom_right = bottom_right;
-
-                result.push((top_left, bottom_right, top_left));
-                result.push((top_left, bottom_right, bottom_right));
+        let (first, second) = match self {
+            NodePair::TopLeftBottomRight(top_left, bottom_right) => (top_left, bottom_right),
+            NodePair::TopRightBottomLeft(top_right, bottom_left) => (top_right, bottom_left),
+        };
+        let row_diff = second.0 as i64 - first.0 as i64;
+        let col_diff = second.1 as i64 - first.1 as i64;
+        let divisor = num::gcd(row_diff, col_diff).expect("grid sized differences can't overflow");
+        let step = (row_diff / divisor, col_diff / divisor);
 
-                while top_left.0 >= row_diff && top_left.1 >= col_diff {
-                    result.push((
-                        top_left,
-                        bottom_right,
-                        (top_left.0 - row_diff, top_left.1 - col_diff),
-                    ));
-                    top_left.0 -= row_diff;
-                    top_left.1 -= col_diff;
-                }
-
-                while bottom_right.0 + row_diff < row_max && bottom_right.1 + col_diff < col_max {
-                    result.push((
-                        top_left,
-                        bottom_right,
-                        (bottom_right.0 + row_diff, bottom_right.1 + col_diff),
-                    ));
-                    bottom_right.0 += row_diff;
-                    bottom_right.1 += col_diff;
-                }
+        let mut result = Vec::with_capacity(4);
+        for direction in [1, -1] {
+            let mut pos = (first.0 as i64, first.1 as i64);
+            if direction < 0 {
+                pos = (pos.0 - step.0, pos.1 - step.1);
             }
-            NodePair::TopRightBottomLeft(top_right, bottom_left) => {
-                let row_diff = bottom_left.0 - top_right.0;
-                let col_diff = top_right.1 - bottom_left.1;
-
-                let mut top_right = top_right;
-                let mut bottom_left = bottom_left;
-
-                result.push((top_right, bottom_left, top_right));
-                result.push((top_right, bottom_left, bottom_left));
-
-                while top_right.0 >= row_diff && top_right.1 + col_diff < col_max {
-                    result.push((
-                        top_right,
-                        bottom_left,
-                        (top_right.0 - row_diff, top_right.1 + col_diff),
-                    ));
-                    top_right.0 -= row_diff;
-                    top_right.1 += col_diff;
-                }
-
-                while bottom_left.0 + row_diff < row_max && bottom_left.1 >= col_diff {
-                    result.push((
-                        top_right,
-                        bottom_left,
-                        (bottom_left.0 + row_diff, bottom_left.1 - col_diff),
-                    ));
-                    bottom_left.0 += row_diff;
-                    bottom_left.1 -= col_diff;
-                }
+            while (0..row_max as i64).contains(&pos.0) && (0..col_max as i64).contains(&pos.1) {
+                result.push((first, second, (pos.0 as usize, pos.1 as usize)));
+                pos = (pos.0 + direction * step.0, pos.1 + direction * step.1);
             }
         }
         result
@@ -196,7 +152,15 @@ where
 }
 
 impl Puzzle for Day8 {
-    fn puzzle_1(contents: String) {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+    const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Math];
+    const HELPERS: &'static [&'static str] = &["num", "read_grid"];
+    const NOTES: &'static str = "Antinodes from every pair of same-frequency antennas, part 2 \
+        walks each pair's whole line";
+
+    fn puzzle_1(contents: String) -> Answer {
         let grid = read_grid(contents);
 
         let antennas = get_antennas(&grid);
@@ -209,10 +173,10 @@ impl Puzzle for Day8 {
 
         let antinode_count = calculate_antinodes(&mut grid, antennas, &mut find_antinodes);
 
-        println!("Number of antinodes is: {antinode_count}");
+        antinode_count.into()
     }
 
-    fn puzzle_2(contents: String) {
+    fn puzzle_2(contents: String) -> Answer {
         let grid = read_grid(contents);
 
         let antennas = get_antennas(&grid);
@@ -225,6 +189,6 @@ impl Puzzle for Day8 {
 
         let antinode_count = calculate_antinodes(&mut grid, antennas, &mut find_antinodes);
 
-        println!("number of anitnodes is: {antinode_count}");
+        antinode_count.into()
     }
 }
@@ -0,0 +1,79 @@
+use std::ops::Range;
+
+use helpers::meta::Tag;
+use helpers::span_set::SpanSet;
+use helpers::{Answer, Puzzle};
+
+pub struct Day9;
+
+// Where each file sits, indexed by id, and the free space between them
+fn disk_spans(contents: &str) -> (Vec<Range<usize>>, SpanSet<usize>) {
+    let mut files = Vec::new();
+    let mut free = SpanSet::new();
+    let mut pos = 0;
+    for (digit_idx, digit_char) in contents.trim().chars().enumerate() {
+        let len = digit_char.to_digit(10).expect("Has to be a digit") as usize;
+        if digit_idx % 2 == 0 {
+            files.push(pos..pos + len);
+        } else {
+            free.insert(pos..pos + len);
+        }
+        pos += len;
+    }
+    (files, free)
+}
+
+fn checksum(id: usize, span: Range<usize>) -> usize {
+    id * span.sum::<usize>()
+}
+
+impl Puzzle for Day9 {
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+    const TAGS: &'static [Tag] = &[Tag::Parsing];
+    const HELPERS: &'static [&'static str] = &["span_set"];
+    const NOTES: &'static str = "Files and free space kept as spans, whole files move into the \
+        first gap they fit";
+
+    fn puzzle_1(contents: String) -> Answer {
+        let (files, mut free) = disk_spans(&contents);
+
+        // Blocks move one at a time from the end into the leftmost free space, so a file fills
+        // gaps from its tail until there's no free space left of it
+        let mut total = 0;
+        for (id, file) in files.into_iter().enumerate().rev() {
+            let mut remaining = file;
+            while let Some(gap) = free.first().filter(|gap| gap.start < remaining.start) {
+                let moved = remaining.len().min(gap.len());
+                total += checksum(id, gap.start..gap.start + moved);
+                free.remove(gap.start..gap.start + moved);
+                free.insert(remaining.end - moved..remaining.end);
+                remaining.end -= moved;
+                if remaining.is_empty() {
+                    break;
+                }
+            }
+            total += checksum(id, remaining);
+        }
+        total.into()
+    }
+
+    fn puzzle_2(contents: String) -> Answer {
+        let (files, mut free) = disk_spans(&contents);
+
+        // Whole files move, highest id first, into the leftmost gap they fit in that's before them
+        let mut total = 0;
+        for (id, file) in files.into_iter().enumerate().rev() {
+            match free.first_fit(file.len(), file.start) {
+                Some(fit) => {
+                    free.remove(fit.clone());
+                    free.insert(file);
+                    total += checksum(id, fit);
+                }
+                None => total += checksum(id, file),
+            }
+        }
+        total.into()
+    }
+}
@@ -0,0 +1,90 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, ChildStdout, Command, Stdio};
+use std::time::{Duration, Instant};
+
+// `aoc serve` on a free port, killed once dropped
+struct Serving {
+    child: Child,
+    addr: String,
+    // Kept open so nothing the server or its children print hits a closed pipe
+    _stdout: BufReader<ChildStdout>,
+}
+
+impl Serving {
+    fn start(time_limit: &str) -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["serve", "--port", "0", "--time-limit", time_limit])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut stdout = BufReader::new(child.stdout.take().unwrap());
+        let mut line = String::new();
+        stdout.read_line(&mut line).unwrap();
+        let addr = line
+            .trim()
+            .trim_start_matches("serving on http://")
+            .to_string();
+        Serving {
+            child,
+            addr,
+            _stdout: stdout,
+        }
+    }
+
+    fn solve(&self, target: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(&self.addr).unwrap();
+        write!(
+            stream,
+            "POST {target} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (status, body.to_string())
+    }
+}
+
+impl Drop for Serving {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+const DAY_1_EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+#[test]
+fn test_solves_in_a_child() {
+    let server = Serving::start("30");
+    let (status, body) = server.solve("/solve/1/1?year=2024", DAY_1_EXAMPLE);
+    assert_eq!(200, status);
+    assert!(body.contains("\"answer\":\"11\""));
+    assert!(body.contains("\"input\":\"request body\""));
+
+    let (status, body) = server.solve("/solve/1/2", DAY_1_EXAMPLE);
+    assert_eq!(200, status);
+    assert!(body.contains("\"answer\":\"31\""));
+
+    let (status, body) = server.solve("/solve/1/1", "not a number\n");
+    assert_eq!(200, status);
+    assert!(body.contains("\"status\":\"panicked\""));
+}
+
+#[test]
+fn test_times_out() {
+    let server = Serving::start("0.5");
+    // Enough buyers to keep day 22 busy for far longer than the limit
+    let buyers = (0..100_000)
+        .map(|buyer| format!("{}\n", buyer + 1))
+        .collect::<String>();
+    let started = Instant::now();
+    let (status, body) = server.solve("/solve/22/2", &buyers);
+    assert_eq!(504, status);
+    assert!(body.contains("didn't finish within 0.500 s"));
+    // The child was killed at the limit rather than waited on
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
@@ -0,0 +1,101 @@
+// Compares SipHash (std's default) against `FxHasher` on the keys the hash heavy days use. Each
+// key hashes exactly as the day's own type does. `binaries/benches/days.rs` times the days
+// themselves.
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use helpers::hash::FxBuildHasher;
+
+// Inserts every key then looks each one up twice, roughly how the days use their maps
+fn insert_and_lookup<K: Hash + Eq + Clone, S: BuildHasher + Default>(keys: &[K]) -> usize {
+    let mut map: HashMap<K, usize, S> = HashMap::default();
+    for (idx, key) in keys.iter().enumerate() {
+        map.insert(key.clone(), idx);
+    }
+    let mut total = 0;
+    for _ in 0..2 {
+        for key in keys {
+            total += map.get(key).copied().unwrap_or_default();
+        }
+    }
+    total
+}
+
+fn compare<K: Hash + Eq + Clone>(
+    c: &mut Criterion,
+    name: &str,
+    keys: &[K],
+    run: fn(&[K], bool) -> usize,
+) {
+    let mut group = c.benchmark_group(name);
+    group.bench_function("siphash", |b| b.iter(|| run(black_box(keys), false)));
+    group.bench_function("fx", |b| b.iter(|| run(black_box(keys), true)));
+    group.finish();
+}
+
+fn map_with<K: Hash + Eq + Clone>(keys: &[K], fx: bool) -> usize {
+    if fx {
+        insert_and_lookup::<K, FxBuildHasher>(keys)
+    } else {
+        insert_and_lookup::<K, RandomState>(keys)
+    }
+}
+
+// Every (stone, blinks left) day 11's memo sees over `blinks` blinks from its example
+fn stone_memo_keys(blinks: usize) -> Vec<(u64, usize)> {
+    let mut keys = Vec::new();
+    let mut stones = vec![125u64, 17];
+    for blink in 0..blinks {
+        keys.extend(stones.iter().map(|stone| (*stone, 75 - blink)));
+        let mut next = stones
+            .iter()
+            .flat_map(|&stone| {
+                let digits = if stone == 0 { 1 } else { stone.ilog10() + 1 };
+                match stone {
+                    0 => vec![1],
+                    _ if digits % 2 == 0 => {
+                        let half = 10u64.pow(digits / 2);
+                        vec![stone / half, stone % half]
+                    }
+                    _ => vec![stone * 2024],
+                }
+            })
+            .collect::<Vec<_>>();
+        next.sort_unstable();
+        next.dedup();
+        stones = next;
+    }
+    keys
+}
+
+fn hashers(c: &mut Criterion) {
+    // Day 11 memoizes `(u64, usize)`, a stone and the blinks left
+    compare(c, "day_11_stone_memo", &stone_memo_keys(40), map_with);
+
+    // Day 16 maps `DirectionalCoord(Coord(row, col), Direction)` to its outgoing edges. The
+    // derived `Hash` writes both usizes then the direction's discriminant as an isize, the same
+    // as this tuple
+    let edges = (0..20_000usize)
+        .map(|idx| ((idx / 141, idx % 141), (idx % 4) as isize))
+        .collect::<Vec<_>>();
+    compare(c, "day_16_edges", &edges, map_with);
+
+    // Day 24 keys `String` wire names, `x00` to `y44` in, `z00` to `z45` out and three letter
+    // names for the gates between
+    let wires = [("x", 45), ("y", 45), ("z", 46)]
+        .into_iter()
+        .flat_map(|(prefix, bits)| (0..bits).map(move |bit| format!("{prefix}{bit:02}")))
+        .chain((0..220usize).map(|idx| {
+            let mixed = idx * 7_919;
+            (0..3)
+                .map(|place| (b'a' + (mixed / 26usize.pow(place) % 26) as u8) as char)
+                .collect()
+        }))
+        .collect::<Vec<String>>();
+    compare(c, "day_24_wires", &wires, map_with);
+}
+
+criterion_group!(benches, hashers);
+criterion_main!(benches);
@@ -0,0 +1,249 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+// Kept per thread so a measurement only sees its own thread's allocations, not those of parts
+// running side by side on others
+thread_local! {
+    static COUNTS: Cell<Counts> = const { Cell::new(Counts::ZERO) };
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Counts {
+    allocations: usize,
+    bytes_allocated: usize,
+    // Signed since a thread can free what another one allocated
+    live_bytes: isize,
+    peak_live_bytes: isize,
+}
+
+impl Counts {
+    const ZERO: Counts = Counts {
+        allocations: 0,
+        bytes_allocated: 0,
+        live_bytes: 0,
+        peak_live_bytes: 0,
+    };
+}
+
+fn counts() -> Counts {
+    COUNTS.try_with(Cell::get).unwrap_or(Counts::ZERO)
+}
+
+fn update_counts(f: impl FnOnce(&mut Counts)) {
+    // Allocations made while the thread is being torn down have nowhere to be counted
+    let _ = COUNTS.try_with(|cell| {
+        let mut counts = cell.get();
+        f(&mut counts);
+        cell.set(counts);
+    });
+}
+
+/// Wraps the system allocator and keeps running totals, per thread, of every allocation made
+/// through it. Installed as the global allocator when the `count-allocs` feature is on.
+pub struct CountingAllocator;
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+impl CountingAllocator {
+    fn record_alloc(size: usize) {
+        update_counts(|counts| {
+            counts.allocations += 1;
+            counts.bytes_allocated += size;
+            counts.live_bytes += size as isize;
+            counts.peak_live_bytes = counts.peak_live_bytes.max(counts.live_bytes);
+        });
+    }
+
+    fn record_dealloc(size: usize) {
+        update_counts(|counts| counts.live_bytes -= size as isize);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record_dealloc(layout.size());
+    }
+
+    // A realloc counts as one new allocation of the new size, matching what a fresh
+    // `alloc` + copy would have cost.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Whether allocations are actually being counted in this build.
+pub fn enabled() -> bool {
+    cfg!(feature = "count-allocs")
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub bytes_allocated: usize,
+    // Highest number of live bytes above what was already live when the measurement started
+    pub peak_live_bytes: usize,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "allocs: {}, allocated: {}, peak: {}",
+            self.allocations,
+            format_bytes(self.bytes_allocated),
+            format_bytes(self.peak_live_bytes)
+        )
+    }
+}
+
+/// Runs `f` and reports the allocations it made. Only this thread's allocations are counted, so
+/// anything `f` hands off to other threads is left out, and so is whatever other threads are
+/// doing at the same time. Measurements can be nested, the outer one counting the inner's too.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let before = counts();
+    // Start a fresh peak for this measurement
+    update_counts(|counts| counts.peak_live_bytes = counts.live_bytes);
+
+    let result = f();
+
+    let after = counts();
+    // An outer measurement's peak covers this one's too
+    update_counts(|counts| {
+        counts.peak_live_bytes = counts.peak_live_bytes.max(before.peak_live_bytes)
+    });
+    let stats = AllocStats {
+        allocations: after.allocations - before.allocations,
+        bytes_allocated: after.bytes_allocated - before.bytes_allocated,
+        peak_live_bytes: (after.peak_live_bytes - before.live_bytes).max(0) as usize,
+    };
+    (result, stats)
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod test_alloc {
+    use std::alloc::{GlobalAlloc, Layout};
+    use std::thread;
+
+    use crate::alloc::{format_bytes, measure, AllocStats, CountingAllocator};
+
+    #[test]
+    fn test_measure_counts_direct_allocations() {
+        let layout = Layout::from_size_align(4096, 8).unwrap();
+        let (_, stats) = measure(|| unsafe {
+            let first = CountingAllocator.alloc(layout);
+            let second = CountingAllocator.alloc(layout);
+            CountingAllocator.dealloc(first, layout);
+            let grown = CountingAllocator.realloc(second, layout, 8192);
+            CountingAllocator.dealloc(grown, Layout::from_size_align(8192, 8).unwrap());
+        });
+        // Counts are per thread, so other tests allocating at the same time don't show up here
+        assert_eq!(
+            AllocStats {
+                allocations: 3,
+                bytes_allocated: 4096 + 4096 + 8192,
+                peak_live_bytes: 8192,
+            },
+            stats
+        );
+    }
+
+    #[test]
+    fn test_measurements_dont_leak_into_each_other() {
+        let big = Layout::from_size_align(1 << 20, 8).unwrap();
+        let small = Layout::from_size_align(64, 8).unwrap();
+        let small_alloc = || unsafe {
+            let ptr = CountingAllocator.alloc(small);
+            CountingAllocator.dealloc(ptr, small);
+        };
+
+        // Neither an earlier peak nor what's still live from before counts
+        unsafe {
+            let ptr = CountingAllocator.alloc(big);
+            CountingAllocator.dealloc(ptr, big);
+        }
+        let held = unsafe { CountingAllocator.alloc(big) };
+        let (inner, outer) = measure(|| {
+            unsafe {
+                let spike = CountingAllocator.alloc(big);
+                CountingAllocator.dealloc(spike, big);
+            }
+            measure(small_alloc).1
+        });
+        let small_stats = AllocStats {
+            allocations: 1,
+            bytes_allocated: 64,
+            peak_live_bytes: 64,
+        };
+        assert_eq!(small_stats, inner);
+        assert_eq!(
+            AllocStats {
+                allocations: 2,
+                bytes_allocated: (1 << 20) + 64,
+                peak_live_bytes: 1 << 20,
+            },
+            outer
+        );
+        assert_eq!(small_stats, measure(small_alloc).1);
+
+        // Nor does another thread allocating while this one measures
+        let (_, stats) = measure(|| {
+            thread::spawn(move || unsafe {
+                let ptr = CountingAllocator.alloc(big);
+                CountingAllocator.dealloc(ptr, big);
+            })
+            .join()
+            .unwrap()
+        });
+        assert!(stats.bytes_allocated < 1 << 20);
+        unsafe { CountingAllocator.dealloc(held, big) };
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!("512 B", format_bytes(512));
+        assert_eq!("1.5 KiB", format_bytes(1536));
+        assert_eq!("3.0 MiB", format_bytes(3 * 1024 * 1024));
+    }
+}
@@ -0,0 +1,241 @@
+use std::fs;
+use std::io;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::cache::Cache;
+use crate::params::sidecar_path;
+use crate::progress;
+use crate::runner::{answers_sidecar_path, input_label, run_part_with, PartReport, Solver};
+
+/// Both parts of a day against one input of a batch.
+#[derive(Debug, Clone)]
+pub struct BatchRow {
+    pub input: String,
+    pub parts: Vec<PartReport>,
+}
+
+/// One worker per core, or just the one when that can't be found out.
+pub fn default_workers() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// `f` applied to every item on a pool of `workers` threads, each taking the next item as soon
+/// as it's done with its last. The results come back in the same order as `items`.
+pub fn parallel_map<T: Send, R: Send>(
+    workers: usize,
+    items: Vec<T>,
+    f: impl Fn(T) -> R + Sync,
+) -> Vec<R> {
+    let total = items.len();
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let results = Mutex::new((0..total).map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, total.max(1)) {
+            scope.spawn(|| loop {
+                // Let go of the queue before running the item so the others can take theirs
+                let next = queue.lock().expect("queue is never poisoned").next();
+                let Some((index, item)) = next else {
+                    break;
+                };
+                let result = f(item);
+                results.lock().expect("results are never poisoned")[index] = Some(result);
+            });
+        }
+    });
+    results
+        .into_inner()
+        .expect("results are never poisoned")
+        .into_iter()
+        .map(|result| result.expect("every item gets run"))
+        .collect()
+}
+
+/// Every input file in `dir` in name order, leaving out hidden files and the `.params` and
+/// `.answers` sidecars that go with the inputs.
+pub fn batch_inputs(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut inputs = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        let sidecar = path == sidecar_path(&path) || path == answers_sidecar_path(&path);
+        if path.is_file() && !hidden && !sidecar {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Runs both parts of `solver` against every input in `dir`, all at once on `workers` threads.
+/// Each input is checked against its own expected answers and picks up its own params sidecar,
+/// same as `run_part_with`.
+///
+/// Progress isn't shown while parts run side by side.
+pub fn run_batch(
+    solver: &Solver,
+    dir: &Path,
+    overrides: &[(String, String)],
+    cache: Option<&Cache>,
+    workers: usize,
+) -> io::Result<Vec<BatchRow>> {
+    let inputs = batch_inputs(dir)?;
+    let jobs = inputs
+        .iter()
+        .flat_map(|input| [(input, 1), (input, 2)])
+        .collect::<Vec<_>>();
+    let mut reports = progress::hidden(|| {
+        parallel_map(workers, jobs, |(input, part)| {
+            run_part_with(solver, part, input, overrides, cache).expect("both parts always exist")
+        })
+    })
+    .into_iter();
+
+    Ok(inputs
+        .iter()
+        .map(|input| BatchRow {
+            input: input_label(input),
+            parts: reports.by_ref().take(2).collect(),
+        })
+        .collect())
+}
+
+/// The answers and solve times for each input, a line per input and a column per part.
+pub fn format_table(rows: &[BatchRow]) -> String {
+    let cell = |report: &PartReport| {
+        let cached = if report.cached { " cached" } else { "" };
+        format!(
+            "{} [{}{cached}]",
+            report.answer.as_deref().unwrap_or("-"),
+            report.status
+        )
+    };
+    let input_width = rows
+        .iter()
+        .map(|row| row.input.len())
+        .chain(["input".len()])
+        .max()
+        .unwrap_or_default();
+    let cell_width = rows
+        .iter()
+        .flat_map(|row| row.parts.iter().map(cell))
+        .map(|cell| cell.len())
+        .chain(["part 1".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut table = format!(
+        "{:<input_width$}  {:<cell_width$}  {:>10}  {:<cell_width$}  {:>10}\n",
+        "input", "part 1", "solve ms", "part 2", "solve ms"
+    );
+    for row in rows {
+        table.push_str(&format!("{:<input_width$}", row.input));
+        for report in &row.parts {
+            table.push_str(&format!(
+                "  {:<cell_width$}  {:>10.3}",
+                cell(report),
+                report.solve_ms
+            ));
+        }
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod test_batch {
+    use std::fs;
+
+    use crate::batch::{batch_inputs, format_table, parallel_map, run_batch};
+    use crate::runner::{Solver, Status};
+    use crate::{Answer, Puzzle};
+
+    struct Summer;
+
+    impl Puzzle for Summer {
+        const YEAR: u32 = 2015;
+        const DAY: u32 = 95;
+
+        fn puzzle_1(contents: String) -> Answer {
+            contents
+                .lines()
+                .map(|line| line.parse::<i64>().unwrap())
+                .sum::<i64>()
+                .into()
+        }
+
+        fn puzzle_2(contents: String) -> Answer {
+            contents.lines().count().into()
+        }
+    }
+
+    #[test]
+    fn test_parallel_map_keeps_order() {
+        let squares = parallel_map(3, (0..50u64).collect(), |n| n * n);
+        assert_eq!((0..50u64).map(|n| n * n).collect::<Vec<_>>(), squares);
+        assert!(parallel_map(4, Vec::<u8>::new(), |n| n).is_empty());
+    }
+
+    #[test]
+    fn test_run_batch() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("bob.txt"), "1\n2\nnope\n").unwrap();
+        fs::write(dir.join("alice.txt"), "1\n2\n3\n").unwrap();
+        fs::write(dir.join("alice.answers"), "6\n4\n").unwrap();
+        fs::write(dir.join("carol"), "5\n").unwrap();
+        fs::write(dir.join("carol.params"), "").unwrap();
+        fs::write(dir.join(".hidden"), "").unwrap();
+        fs::write(dir.join("dave.txt"), b"\xff\xfe").unwrap();
+
+        let names = batch_inputs(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["alice.txt", "bob.txt", "carol", "dave.txt"], names);
+
+        let rows = run_batch(&Solver::of::<Summer>(), &dir, &[], None, 4).unwrap();
+        let statuses = rows
+            .iter()
+            .map(|row| {
+                let parts = row.parts.iter().map(|report| (report.part, report.status));
+                (row.input.as_str(), parts.collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (
+                    "alice.txt",
+                    vec![(1, Status::Correct), (2, Status::Incorrect)]
+                ),
+                (
+                    "bob.txt",
+                    vec![(1, Status::Panicked), (2, Status::Unverified)]
+                ),
+                (
+                    "carol",
+                    vec![(1, Status::Unverified), (2, Status::Unverified)]
+                ),
+                (
+                    "dave.txt",
+                    vec![(1, Status::InvalidInput), (2, Status::InvalidInput)]
+                ),
+            ],
+            statuses
+        );
+
+        let table = format_table(&rows);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(5, lines.len());
+        assert!(lines[0].starts_with("input      part 1         "));
+        assert!(lines[1].starts_with("alice.txt  6 [correct]    "));
+        assert!(lines[2].contains("- [panicked]"));
+        assert!(lines[4].contains("- [invalid input]"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,276 @@
+use std::ops::{BitAnd, BitOr, Not};
+
+/// A grid of booleans packed 64 to a word, each row starting on a fresh word so whole rows can
+/// be combined a word at a time. Bits past `width` in a row's last word are always kept clear.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(64);
+        BitGrid {
+            width,
+            height,
+            words_per_row,
+            words: vec![0; words_per_row * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn row(&self, row: usize) -> &[u64] {
+        &self.words[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    fn row_mut(&mut self, row: usize) -> &mut [u64] {
+        &mut self.words[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        assert!(col < self.width, "column {col} is outside the grid");
+        self.row(row)[col / 64] & (1 << (col % 64)) != 0
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: bool) {
+        assert!(col < self.width, "column {col} is outside the grid");
+        let word = &mut self.row_mut(row)[col / 64];
+        if value {
+            *word |= 1 << (col % 64);
+        } else {
+            *word &= !(1 << (col % 64));
+        }
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    // Which bits of a row's last word are inside the grid
+    fn tail_mask(&self) -> u64 {
+        match self.width % 64 {
+            0 => u64::MAX,
+            used => (1 << used) - 1,
+        }
+    }
+
+    fn clear_tail(&mut self) {
+        let mask = self.tail_mask();
+        if self.words_per_row == 0 {
+            return;
+        }
+        for row in self.words.chunks_exact_mut(self.words_per_row) {
+            row[self.words_per_row - 1] &= mask;
+        }
+    }
+
+    /// Moves every cell `down` rows and `right` columns (negative to go up or left). Cells moved
+    /// off the grid are dropped and the ones uncovered are unset.
+    pub fn shifted(&self, down: isize, right: isize) -> BitGrid {
+        let mut result = BitGrid::new(self.width, self.height);
+        for row in 0..self.height {
+            let Some(source) = row
+                .checked_add_signed(-down)
+                .filter(|source| *source < self.height)
+            else {
+                continue;
+            };
+            shift_words(self.row(source), result.row_mut(row), right);
+        }
+        result.clear_tail();
+        result
+    }
+
+    fn zip_with(&self, other: &BitGrid, f: impl Fn(u64, u64) -> u64) -> BitGrid {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "grids have to be the same size"
+        );
+        BitGrid {
+            words: self
+                .words
+                .iter()
+                .zip(other.words.iter())
+                .map(|(a, b)| f(*a, *b))
+                .collect(),
+            ..*self
+        }
+    }
+
+    // The grid shifted one step towards each of its 4 neighbours
+    fn neighbours(&self) -> [BitGrid; 4] {
+        [
+            self.shifted(1, 0),
+            self.shifted(-1, 0),
+            self.shifted(0, 1),
+            self.shifted(0, -1),
+        ]
+    }
+
+    /// Keeps only cells whose 4 neighbours are all set too. Anything off the grid counts as unset
+    /// so the border always erodes away.
+    pub fn erode(&self) -> BitGrid {
+        self.neighbours()
+            .iter()
+            .fold(self.clone(), |acc, neighbour| &acc & neighbour)
+    }
+
+    /// Sets every cell with at least one of its 4 neighbours set.
+    pub fn dilate(&self) -> BitGrid {
+        self.neighbours()
+            .iter()
+            .fold(self.clone(), |acc, neighbour| &acc | neighbour)
+    }
+}
+
+// Shifts a row's bits towards higher columns by `right` (lower if negative), filling with zeros
+fn shift_words(source: &[u64], target: &mut [u64], right: isize) {
+    let len = source.len();
+    let (words, bits) = (right.unsigned_abs() / 64, right.unsigned_abs() % 64);
+    for (idx, word) in target.iter_mut().enumerate() {
+        *word = if right >= 0 {
+            let whole = idx.checked_sub(words).map(|from| source[from] << bits);
+            let carry = idx
+                .checked_sub(words + 1)
+                .filter(|_| bits > 0)
+                .map(|from| source[from] >> (64 - bits));
+            whole.unwrap_or(0) | carry.unwrap_or(0)
+        } else {
+            let whole = (idx + words < len).then(|| source[idx + words] >> bits);
+            let carry =
+                (bits > 0 && idx + words + 1 < len).then(|| source[idx + words + 1] << (64 - bits));
+            whole.unwrap_or(0) | carry.unwrap_or(0)
+        };
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, other: &BitGrid) -> BitGrid {
+        self.zip_with(other, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, other: &BitGrid) -> BitGrid {
+        self.zip_with(other, |a, b| a | b)
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mut result = BitGrid {
+            words: self.words.iter().map(|word| !word).collect(),
+            ..*self
+        };
+        result.clear_tail();
+        result
+    }
+}
+
+#[cfg(test)]
+mod test_bit_grid {
+    use crate::bit_grid::BitGrid;
+
+    fn from_rows(rows: &[&str]) -> BitGrid {
+        let mut grid = BitGrid::new(rows[0].len(), rows.len());
+        for (row, line) in rows.iter().enumerate() {
+            for (col, char) in line.chars().enumerate() {
+                grid.set(row, col, char == '#');
+            }
+        }
+        grid
+    }
+
+    #[test]
+    fn test_set_get_count() {
+        let mut grid = BitGrid::new(101, 3);
+        grid.set(0, 0, true);
+        grid.set(1, 63, true);
+        grid.set(1, 64, true);
+        grid.set(2, 100, true);
+        assert!(grid.get(1, 64));
+        assert!(!grid.get(1, 65));
+        assert_eq!(4, grid.count_ones());
+        grid.set(1, 63, false);
+        assert_eq!(3, grid.count_ones());
+        assert_eq!(2, grid.row(1).len());
+    }
+
+    #[test]
+    fn test_shift_crosses_words() {
+        let mut grid = BitGrid::new(130, 2);
+        grid.set(0, 62, true);
+        grid.set(0, 129, true);
+
+        let right = grid.shifted(1, 3);
+        assert!(right.get(1, 65));
+        // Shifted off the right edge
+        assert_eq!(1, right.count_ones());
+
+        let left = grid.shifted(0, -66);
+        assert!(left.get(0, 63));
+        assert_eq!(1, left.count_ones());
+
+        assert_eq!(0, grid.shifted(2, 0).count_ones());
+    }
+
+    #[test]
+    fn test_not_stays_inside_the_grid() {
+        let grid = BitGrid::new(70, 2);
+        assert_eq!(140, (!&grid).count_ones());
+
+        let mut other = BitGrid::new(70, 2);
+        other.set(0, 69, true);
+        assert_eq!(1, (&!&grid & &other).count_ones());
+        assert_eq!(140, (&!&grid | &other).count_ones());
+    }
+
+    #[test]
+    fn test_erode_and_dilate() {
+        let grid = from_rows(&[
+            "#####.", //
+            "#####.", //
+            "#####.", //
+            "......",
+        ]);
+        let eroded = grid.erode();
+        assert_eq!(
+            from_rows(&[
+                "......", //
+                ".###..", //
+                "......", //
+                "......",
+            ]),
+            eroded
+        );
+        assert_eq!(
+            from_rows(&[
+                ".###..", //
+                "#####.", //
+                ".###..", //
+                "......",
+            ]),
+            eroded.dilate()
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt::Display;
+
+/// A fixed-width run of bits, most significant first. The width is part of the value so leading
+/// zeros are kept, `0011` and `11` are different `BitVec`s.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    bits: Vec<bool>,
+}
+
+impl BitVec {
+    pub fn zeros(width: usize) -> Self {
+        BitVec {
+            bits: vec![false; width],
+        }
+    }
+
+    /// `value` written out in `width` bits. Panics if it needs more than that.
+    pub fn from_int<T: TryInto<u128>>(value: T, width: usize) -> Self {
+        let value: u128 = value
+            .try_into()
+            .unwrap_or_else(|_| panic!("only non-negative values have bits"));
+        assert!(
+            width >= 128 || value >> width == 0,
+            "{value} doesn't fit in {width} bits"
+        );
+        BitVec::from_lsb((0..width).map(|idx| idx < 128 && (value >> idx) & 1 == 1))
+    }
+
+    pub fn from_msb(bits: impl IntoIterator<Item = bool>) -> Self {
+        BitVec {
+            bits: bits.into_iter().collect(),
+        }
+    }
+
+    pub fn from_lsb(bits: impl IntoIterator<Item = bool>) -> Self {
+        let mut bits = bits.into_iter().collect::<Vec<_>>();
+        bits.reverse();
+        BitVec { bits }
+    }
+
+    /// `None` if the value doesn't fit in `T`.
+    pub fn to_int<T: TryFrom<u128>>(&self) -> Option<T> {
+        let value = self.bits.iter().try_fold(0u128, |acc, bit| {
+            acc.checked_mul(2).map(|acc| acc | *bit as u128)
+        })?;
+        T::try_from(value).ok()
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    /// The bit worth `2^idx`.
+    pub fn bit(&self, idx: usize) -> bool {
+        self.bits[self.bits.len() - 1 - idx]
+    }
+
+    pub fn set_bit(&mut self, idx: usize, value: bool) {
+        let len = self.bits.len();
+        self.bits[len - 1 - idx] = value;
+    }
+
+    pub fn iter_msb(&self) -> impl DoubleEndedIterator<Item = bool> + '_ {
+        self.bits.iter().copied()
+    }
+
+    pub fn iter_lsb(&self) -> impl DoubleEndedIterator<Item = bool> + '_ {
+        self.bits.iter().rev().copied()
+    }
+
+    /// Shifts everything up to make room for `suffix` as the new low bits.
+    pub fn push_suffix(&mut self, suffix: &BitVec) {
+        self.bits.extend_from_slice(&suffix.bits);
+    }
+
+    /// Removes and returns the lowest `width` bits.
+    pub fn split_off_suffix(&mut self, width: usize) -> BitVec {
+        BitVec {
+            bits: self.bits.split_off(self.bits.len() - width),
+        }
+    }
+
+    /// The lowest `width` bits.
+    pub fn suffix(&self, width: usize) -> BitVec {
+        BitVec {
+            bits: self.bits[self.bits.len() - width..].to_vec(),
+        }
+    }
+
+    /// Every `BitVec` of `width` bits, counting up from all zeros.
+    pub fn all(width: usize) -> impl Iterator<Item = BitVec> {
+        assert!(width < 128, "too many to go through");
+        (0..1u128 << width).map(move |value| BitVec::from_int(value, width))
+    }
+
+    /// This as a fixed prefix followed by every possible suffix of `width` bits, in order.
+    pub fn with_suffixes(&self, width: usize) -> impl Iterator<Item = BitVec> + '_ {
+        BitVec::all(width).map(|suffix| {
+            let mut whole = self.clone();
+            whole.push_suffix(&suffix);
+            whole
+        })
+    }
+
+    /// Lines the two up by their lowest bits, counting any missing high bits as zeros.
+    pub fn diff<'a>(&'a self, other: &'a BitVec) -> BitDiff<'a> {
+        BitDiff {
+            expected: self,
+            actual: other,
+        }
+    }
+}
+
+impl Display for BitVec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for bit in self.iter_msb() {
+            write!(f, "{}", bit as u8)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct BitDiff<'a> {
+    expected: &'a BitVec,
+    actual: &'a BitVec,
+}
+
+impl BitDiff<'_> {
+    fn width(&self) -> usize {
+        self.expected.len().max(self.actual.len())
+    }
+
+    fn bit(bits: &BitVec, idx: usize) -> bool {
+        idx < bits.len() && bits.bit(idx)
+    }
+
+    /// Where the two differ, as `2^idx` positions from the lowest up.
+    pub fn mismatches(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.width())
+            .filter(|idx| Self::bit(self.expected, *idx) != Self::bit(self.actual, *idx))
+    }
+}
+
+// Three rows, bit positions over the expected and actual bits, with mismatches marked below
+impl Display for BitDiff<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let positions = (0..self.width()).rev().collect::<Vec<_>>();
+        let row = |f: &mut std::fmt::Formatter<'_>, label: &str, cell: &dyn Fn(usize) -> String| {
+            write!(f, "{label: <9}")?;
+            for idx in &positions {
+                write!(f, "{: >3}", cell(*idx))?;
+            }
+            writeln!(f)
+        };
+        row(f, "bit", &|idx| idx.to_string())?;
+        row(f, "expected", &|idx| {
+            (Self::bit(self.expected, idx) as u8).to_string()
+        })?;
+        row(f, "actual", &|idx| {
+            (Self::bit(self.actual, idx) as u8).to_string()
+        })?;
+        row(f, "", &|idx| {
+            if Self::bit(self.expected, idx) != Self::bit(self.actual, idx) {
+                "^".to_string()
+            } else {
+                String::new()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_bit_vec {
+    use crate::bit_vec::BitVec;
+
+    #[test]
+    fn test_int_round_trip() {
+        let bits = BitVec::from_int(11u8, 6);
+        assert_eq!("001011", bits.to_string());
+        assert_eq!(Some(11usize), bits.to_int());
+        assert!(bits.bit(0) && bits.bit(1) && !bits.bit(2) && bits.bit(3));
+        assert_eq!(
+            vec![true, true, false, true, false, false],
+            bits.iter_lsb().collect::<Vec<_>>()
+        );
+        assert_eq!(bits, BitVec::from_lsb(bits.iter_lsb()));
+        assert_eq!(bits, BitVec::from_msb(bits.iter_msb()));
+
+        let mut wide = BitVec::from_int(u128::MAX, 130);
+        assert_eq!(Some(u128::MAX), wide.to_int());
+        wide.set_bit(129, true);
+        assert_eq!(None, wide.to_int::<u128>());
+        assert_eq!(Some(u128::MAX), wide.suffix(128).to_int());
+        assert_eq!(None, BitVec::from_int(300u32, 9).to_int::<u8>());
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't fit")]
+    fn test_from_int_too_wide() {
+        BitVec::from_int(8, 3);
+    }
+
+    #[test]
+    fn test_prefix_and_suffixes() {
+        let prefix = BitVec::from_int(0b10, 2);
+        let values = prefix
+            .with_suffixes(3)
+            .map(|bits| bits.to_int::<u32>().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!((16..24).collect::<Vec<_>>(), values);
+
+        let mut bits = BitVec::from_int(0b10110, 5);
+        let suffix = bits.split_off_suffix(3);
+        assert_eq!(("10", "110"), (&*bits.to_string(), &*suffix.to_string()));
+        bits.push_suffix(&BitVec::zeros(2));
+        assert_eq!("1000", bits.to_string());
+        assert_eq!(4, BitVec::all(2).count());
+    }
+
+    #[test]
+    fn test_diff() {
+        let expected = BitVec::from_int(0b1010, 4);
+        let actual = BitVec::from_int(0b110, 3);
+        let diff = expected.diff(&actual);
+        assert_eq!(vec![2, 3], diff.mismatches().collect::<Vec<_>>());
+        assert_eq!(
+            [
+                "bit        3  2  1  0",
+                "expected   1  0  1  0",
+                "actual     0  1  1  0",
+                "           ^  ^",
+            ]
+            .join("\n"),
+            diff.to_string().trim_end()
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::params::Params;
+use crate::runner::{hash_input, Solver};
+
+/// Answers from earlier runs, kept in `.aoc/cache/` under the workspace root. An answer is only
+/// reused for the same day, part, input, params and variant, built from the same source.
+pub struct Cache {
+    dir: PathBuf,
+    fingerprints: Fingerprints,
+}
+
+/// Hashes of the source the running binary was built from, worked out at build time so a stale
+/// binary never caches its answers under newer source.
+#[derive(Debug, Clone, Copy)]
+pub struct Fingerprints {
+    // The helpers and the locked dependencies, any change to them could change any answer
+    pub shared: &'static str,
+    // Each day's own module by year and day
+    pub days: &'static [(u32, u32, &'static str)],
+}
+
+impl Fingerprints {
+    fn day(&self, year: u32, day: u32) -> Option<&'static str> {
+        self.days
+            .iter()
+            .find(|(y, d, _)| (*y, *d) == (year, day))
+            .map(|(_, _, fingerprint)| *fingerprint)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedAnswer {
+    pub answer: String,
+    // How long it took to solve when it was cached
+    pub solve_ms: f64,
+}
+
+impl Cache {
+    pub fn new(root: &Path, fingerprints: Fingerprints) -> Self {
+        Cache {
+            dir: root.join(".aoc").join("cache"),
+            fingerprints,
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// What an answer for `part` of `solver` against this input and params is cached under.
+    /// `None` when the day wasn't built from a module of its own, as there'd be no telling when
+    /// it changed.
+    pub fn key(
+        &self,
+        solver: &Solver,
+        part: u8,
+        input_hash: &str,
+        params: &Params,
+    ) -> Option<String> {
+        let source = self.fingerprints.day(solver.year, solver.day)?;
+        let params = serde_json::to_string(params).expect("params are always serializable");
+        let key = format!(
+            "{}\n{}\n{part}\n{}\n{input_hash}\n{params}\n{source}\n{}",
+            solver.year, solver.day, solver.variant, self.fingerprints.shared
+        );
+        Some(format!(
+            "{}-day-{}-part-{part}-{}",
+            solver.year,
+            solver.day,
+            hash_input(key.as_bytes())
+        ))
+    }
+
+    pub fn get(&self, key: &str) -> Option<CachedAnswer> {
+        let json = fs::read_to_string(self.entry_path(key)).ok()?;
+        // Anything unreadable is treated as a miss and overwritten by the next run
+        serde_json::from_str(&json).ok()
+    }
+
+    pub fn put(&self, key: &str, answer: &CachedAnswer) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(
+            self.entry_path(key),
+            serde_json::to_string(answer).expect("answers are always serializable"),
+        )
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+}
+
+#[cfg(test)]
+mod test_cache {
+    use std::fs;
+
+    use crate::cache::{Cache, CachedAnswer, Fingerprints};
+    use crate::params::{Param, Params};
+    use crate::runner::Solver;
+    use crate::{Answer, Puzzle};
+
+    struct Tripler;
+
+    impl Puzzle for Tripler {
+        const YEAR: u32 = 2015;
+        const DAY: u32 = 97;
+        const PARAMS: &'static [Param] = &[Param::int("factor", 3)];
+
+        fn puzzle_1(contents: String) -> Answer {
+            (contents.trim().parse::<i64>().unwrap() * 3).into()
+        }
+
+        fn puzzle_2(contents: String) -> Answer {
+            Tripler::puzzle_1(contents)
+        }
+    }
+
+    const FIRST_TRY: Fingerprints = Fingerprints {
+        shared: "helpers",
+        days: &[(2015, 97, "first try")],
+    };
+
+    #[test]
+    fn test_key_follows_source_and_params() {
+        let root = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let solver = Solver::of::<Tripler>();
+        let params = Params::defaults(solver.params);
+
+        let unbuilt = Fingerprints {
+            shared: "helpers",
+            days: &[],
+        };
+        assert!(Cache::new(&root, unbuilt)
+            .key(&solver, 1, "abc", &params)
+            .is_none());
+        let cache = Cache::new(&root, FIRST_TRY);
+        let key = cache.key(&solver, 1, "abc", &params).unwrap();
+        assert!(key.starts_with("2015-day-97-part-1-"));
+        assert_eq!(Some(&key), cache.key(&solver, 1, "abc", &params).as_ref());
+        assert_ne!(Some(&key), cache.key(&solver, 2, "abc", &params).as_ref());
+        assert_ne!(Some(&key), cache.key(&solver, 1, "abd", &params).as_ref());
+        let other_params = Params::defaults(&[Param::int("factor", 4)]);
+        assert_ne!(
+            Some(&key),
+            cache.key(&solver, 1, "abc", &other_params).as_ref()
+        );
+
+        let answer = CachedAnswer {
+            answer: "63".to_string(),
+            solve_ms: 1.5,
+        };
+        assert!(cache.get(&key).is_none());
+        cache.put(&key, &answer).unwrap();
+        assert_eq!(Some(answer), cache.get(&key));
+
+        // A binary built from an edited day or edited helpers leaves the old answer behind
+        let edited_day = Fingerprints {
+            shared: "helpers",
+            days: &[(2015, 97, "second try")],
+        };
+        let edited = Cache::new(&root, edited_day)
+            .key(&solver, 1, "abc", &params)
+            .unwrap();
+        assert_ne!(key, edited);
+        let edited_helpers = Fingerprints {
+            shared: "edited helpers",
+            ..FIRST_TRY
+        };
+        assert_ne!(
+            Some(key),
+            Cache::new(&root, edited_helpers).key(&solver, 1, "abc", &params)
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
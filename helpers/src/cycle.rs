@@ -0,0 +1,194 @@
+use std::hash::Hash;
+
+use crate::hash::FastMap;
+
+/// Where a sequence `start, f(start), f(f(start)), ...` starts repeating. The states at steps
+/// `lead_in..lead_in + length` make up the cycle and every later step is one of them again.
+///
+/// The detectors only finish if the sequence does repeat, so the state space has to be finite.
+/// Something like a guard walking off the grid should be modelled as an absorbing state (`None`
+/// stepping to `None`), which shows up as a cycle of length 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub lead_in: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as `step`.
+    pub fn reduce(&self, step: usize) -> usize {
+        if step < self.lead_in {
+            step
+        } else {
+            self.lead_in + (step - self.lead_in) % self.length
+        }
+    }
+
+    /// The state after `n` steps, only ever walking as far as the end of the first cycle.
+    pub fn state_at<S: Clone>(&self, start: &S, f: impl Fn(&S) -> S, n: usize) -> S {
+        iterate(start, f, self.reduce(n))
+    }
+}
+
+/// The state after `n` steps, walking all of them.
+pub fn iterate<S: Clone>(start: &S, f: impl Fn(&S) -> S, n: usize) -> S {
+    (0..n).fold(start.clone(), |state, _| f(&state))
+}
+
+/// Floyd's tortoise and hare. Holds two states at a time but calls `f` about three times per step
+/// of the lead-in and cycle.
+pub fn floyd<S: PartialEq + Clone>(start: &S, f: impl Fn(&S) -> S) -> Cycle {
+    // Meet somewhere in the cycle at a step that's a multiple of its length
+    let mut tortoise = f(start);
+    let mut hare = f(&f(start));
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&f(&hare));
+    }
+
+    // A multiple of the length ahead of the start, so they line up once both reach the cycle
+    let mut lead_in = 0;
+    let mut tortoise = start.clone();
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        lead_in += 1;
+    }
+
+    let mut length = 1;
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        length += 1;
+    }
+    Cycle { lead_in, length }
+}
+
+/// Brent's algorithm. Finds the length first by comparing against checkpoints at powers of two,
+/// which usually takes fewer calls to `f` than `floyd`.
+pub fn brent<S: PartialEq + Clone>(start: &S, f: impl Fn(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut checkpoint = start.clone();
+    let mut hare = f(start);
+    while checkpoint != hare {
+        if power == length {
+            checkpoint = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = f(&hare);
+        length += 1;
+    }
+
+    // Start the hare a whole cycle ahead and they'll meet at the first state of the cycle
+    let mut lead_in = 0;
+    let mut tortoise = start.clone();
+    let mut hare = iterate(start, &f, length);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        lead_in += 1;
+    }
+    Cycle { lead_in, length }
+}
+
+/// Remembers the step every state was first seen at, so it calls `f` exactly once per step up to
+/// the first repeat. Uses the most memory but is the one to reach for when `f` is expensive.
+pub fn first_repeat<S: Hash + Eq + Clone>(start: &S, f: impl Fn(&S) -> S) -> Cycle {
+    let mut seen = FastMap::default();
+    let mut state = start.clone();
+    let mut step = 0;
+    loop {
+        if let Some(first) = seen.insert(state.clone(), step) {
+            return Cycle {
+                lead_in: first,
+                length: step - first,
+            };
+        }
+        state = f(&state);
+        step += 1;
+    }
+}
+
+#[cfg(test)]
+mod test_cycle {
+    use crate::cycle::{brent, first_repeat, floyd, iterate, Cycle};
+    use crate::hash::FastMap;
+
+    // Walks every state and records where it was first seen
+    fn brute_force(start: u64, f: impl Fn(&u64) -> u64) -> Cycle {
+        let mut seen = FastMap::default();
+        let mut state = start;
+        for step in 0.. {
+            if let Some(first) = seen.get(&state) {
+                return Cycle {
+                    lead_in: *first,
+                    length: step - first,
+                };
+            }
+            seen.insert(state, step);
+            state = f(&state);
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn test_detectors_agree_with_brute_force() {
+        for modulus in 1..60u64 {
+            for multiplier in 0..8u64 {
+                let f = |x: &u64| (x * x * multiplier + 3) % modulus;
+                let expected = brute_force(1 % modulus, f);
+                assert_eq!(
+                    expected,
+                    floyd(&(1 % modulus), f),
+                    "floyd {modulus} {multiplier}"
+                );
+                assert_eq!(
+                    expected,
+                    brent(&(1 % modulus), f),
+                    "brent {modulus} {multiplier}"
+                );
+                assert_eq!(
+                    expected,
+                    first_repeat(&(1 % modulus), f),
+                    "first_repeat {modulus} {multiplier}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_absorbing_state() {
+        // Counts down past 0 to `None` and stays there
+        let f = |x: &Option<u32>| x.and_then(|x| x.checked_sub(1));
+        let expected = Cycle {
+            lead_in: 6,
+            length: 1,
+        };
+        assert_eq!(expected, floyd(&Some(5), f));
+        assert_eq!(expected, brent(&Some(5), f));
+        assert_eq!(expected, first_repeat(&Some(5), f));
+    }
+
+    #[test]
+    fn test_state_at_jumps_ahead() {
+        // 10 once, then 5, 1, 4, 0, 3, 6, 2 around the cycle
+        let f = |x: &u64| if *x < 7 { (x + 3) % 7 } else { x - 5 };
+        let start = 10;
+        let cycle = brent(&start, f);
+        assert_eq!(
+            Cycle {
+                lead_in: 1,
+                length: 7
+            },
+            cycle
+        );
+        for n in 0..40 {
+            assert_eq!(iterate(&start, f, n), cycle.state_at(&start, f, n));
+        }
+        assert_eq!(iterate(&start, f, 9), cycle.state_at(&start, f, 1 << 40));
+        // 999_999 steps into the cycle is a whole number of laps
+        assert_eq!(4, cycle.reduce(1_000_003));
+    }
+}
@@ -0,0 +1,284 @@
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::hash::FastMap;
+use crate::{params, workspace_root};
+
+/// The bool param a day declares to get a `.dot` file out of `aoc run --emit-dot`.
+pub const PARAM: &str = "emit_dot";
+
+/// A Graphviz graph, written out in DOT by `Display`. Nodes are created the first time they're
+/// named, whether that's through `node` or as one end of an `edge`.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    name: String,
+    directed: bool,
+    nodes: Vec<Node>,
+    node_index: FastMap<String, usize>,
+    edges: Vec<Edge>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    id: String,
+    cluster: Option<String>,
+    attrs: Attrs,
+}
+
+#[derive(Debug, Clone)]
+pub struct Edge {
+    from: String,
+    to: String,
+    attrs: Attrs,
+}
+
+// Setting an attribute twice keeps the last value
+#[derive(Debug, Clone, Default)]
+struct Attrs(Vec<(&'static str, String)>);
+
+impl Attrs {
+    fn set(&mut self, key: &'static str, value: String) {
+        match self.0.iter_mut().find(|(existing, _)| *existing == key) {
+            Some((_, current)) => *current = value,
+            None => self.0.push((key, value)),
+        }
+    }
+}
+
+impl Display for Attrs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        let attrs = self
+            .0
+            .iter()
+            .map(|(key, value)| format!("{key}={}", quote(value)))
+            .collect::<Vec<_>>();
+        write!(f, " [{}]", attrs.join(", "))
+    }
+}
+
+// DOT ids and values are always written quoted so any text can be used
+fn quote(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+impl Node {
+    pub fn label(&mut self, label: impl Into<String>) -> &mut Self {
+        self.attr("label", label)
+    }
+
+    /// The outline colour, any Graphviz colour name or `#rrggbb`.
+    pub fn color(&mut self, color: impl Into<String>) -> &mut Self {
+        self.attr("color", color)
+    }
+
+    /// Fills the node in with `color`.
+    pub fn fill(&mut self, color: impl Into<String>) -> &mut Self {
+        self.attr("style", "filled").attr("fillcolor", color)
+    }
+
+    pub fn shape(&mut self, shape: impl Into<String>) -> &mut Self {
+        self.attr("shape", shape)
+    }
+
+    /// Draws the node inside a box with every other node in the same cluster, labelled `cluster`.
+    pub fn cluster(&mut self, cluster: impl Into<String>) -> &mut Self {
+        self.cluster = Some(cluster.into());
+        self
+    }
+
+    pub fn attr(&mut self, key: &'static str, value: impl Into<String>) -> &mut Self {
+        self.attrs.set(key, value.into());
+        self
+    }
+}
+
+impl Edge {
+    pub fn label(&mut self, label: impl Into<String>) -> &mut Self {
+        self.attr("label", label)
+    }
+
+    pub fn color(&mut self, color: impl Into<String>) -> &mut Self {
+        self.attr("color", color)
+    }
+
+    pub fn attr(&mut self, key: &'static str, value: impl Into<String>) -> &mut Self {
+        self.attrs.set(key, value.into());
+        self
+    }
+}
+
+impl Graph {
+    pub fn directed(name: impl Into<String>) -> Self {
+        Graph::new(name.into(), true)
+    }
+
+    pub fn undirected(name: impl Into<String>) -> Self {
+        Graph::new(name.into(), false)
+    }
+
+    fn new(name: String, directed: bool) -> Self {
+        Graph {
+            name,
+            directed,
+            nodes: Vec::new(),
+            node_index: FastMap::default(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// The node called `id`, added if it isn't in the graph yet.
+    pub fn node(&mut self, id: &str) -> &mut Node {
+        let idx = match self.node_index.get(id) {
+            Some(idx) => *idx,
+            None => {
+                self.nodes.push(Node {
+                    id: id.to_string(),
+                    cluster: None,
+                    attrs: Attrs::default(),
+                });
+                self.node_index.insert(id.to_string(), self.nodes.len() - 1);
+                self.nodes.len() - 1
+            }
+        };
+        &mut self.nodes[idx]
+    }
+
+    /// Adds an edge, and either end that isn't in the graph yet.
+    pub fn edge(&mut self, from: &str, to: &str) -> &mut Edge {
+        self.node(from);
+        self.node(to);
+        self.edges.push(Edge {
+            from: from.to_string(),
+            to: to.to_string(),
+            attrs: Attrs::default(),
+        });
+        self.edges.last_mut().expect("just pushed an edge")
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+impl Display for Graph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (keyword, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        writeln!(f, "{keyword} {} {{", quote(&self.name))?;
+
+        // Clusters come out in the order they were first used
+        let mut clusters: Vec<(&str, Vec<&Node>)> = Vec::new();
+        for node in &self.nodes {
+            match &node.cluster {
+                None => writeln!(f, "  {}{};", quote(&node.id), node.attrs)?,
+                Some(cluster) => match clusters.iter_mut().find(|(name, _)| name == cluster) {
+                    Some((_, members)) => members.push(node),
+                    None => clusters.push((cluster, vec![node])),
+                },
+            }
+        }
+        for (idx, (name, members)) in clusters.iter().enumerate() {
+            // Graphviz only draws subgraphs as boxes when their id starts with "cluster"
+            writeln!(f, "  subgraph \"cluster_{idx}\" {{")?;
+            writeln!(f, "    label={};", quote(name))?;
+            for node in members {
+                writeln!(f, "    {}{};", quote(&node.id), node.attrs)?;
+            }
+            writeln!(f, "  }}")?;
+        }
+
+        for edge in &self.edges {
+            writeln!(
+                f,
+                "  {} {arrow} {}{};",
+                quote(&edge.from),
+                quote(&edge.to),
+                edge.attrs
+            )?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// Writes `graph` to `reports/dot/<name>.dot`, returning where it went.
+pub fn write(name: &str, graph: &Graph) -> io::Result<PathBuf> {
+    let dir = workspace_root().join("reports").join("dot");
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{name}.dot"));
+    fs::write(&path, graph.to_string())?;
+    Ok(path)
+}
+
+/// Builds and writes the graph only when the running part has `PARAM` set, so days can leave
+/// the call in without paying for it on normal runs.
+pub fn emit_if_requested(name: &str, build: impl FnOnce() -> Graph) {
+    if !params::bool(PARAM) {
+        return;
+    }
+    match write(name, &build()) {
+        Ok(path) => println!("wrote dot graph to {:?}", path),
+        Err(err) => println!("couldn't write dot graph {name}: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod test_dot {
+    use crate::dot::Graph;
+
+    #[test]
+    fn test_directed_with_clusters() {
+        let mut graph = Graph::directed("adder");
+        graph.node("x00").cluster("inputs");
+        graph.node("z00").cluster("outputs").fill("red");
+        graph.node("y00").cluster("inputs").label("y \"zero\"");
+        graph.edge("x00", "z00").label("XOR");
+        graph.edge("y00", "z00").color("blue").color("green");
+        assert_eq!((3, 2), (graph.node_count(), graph.edge_count()));
+        assert_eq!(
+            [
+                "digraph \"adder\" {",
+                "  subgraph \"cluster_0\" {",
+                "    label=\"inputs\";",
+                "    \"x00\";",
+                "    \"y00\" [label=\"y \\\"zero\\\"\"];",
+                "  }",
+                "  subgraph \"cluster_1\" {",
+                "    label=\"outputs\";",
+                "    \"z00\" [style=\"filled\", fillcolor=\"red\"];",
+                "  }",
+                "  \"x00\" -> \"z00\" [label=\"XOR\"];",
+                "  \"y00\" -> \"z00\" [color=\"green\"];",
+                "}",
+            ]
+            .join("\n"),
+            graph.to_string().trim_end()
+        );
+    }
+
+    #[test]
+    fn test_undirected() {
+        let mut graph = Graph::undirected("lan");
+        graph.edge("ka", "co");
+        graph.node("ka").color("red");
+        assert_eq!(
+            "graph \"lan\" {\n  \"ka\" [color=\"red\"];\n  \"co\";\n  \"ka\" -- \"co\";\n}\n",
+            graph.to_string()
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasherDefault, Hasher};
+
+// From rustc's FxHasher, picked for how well it spreads small integer keys
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+/// A fast non-cryptographic hasher: each word is folded in with a rotate, xor and multiply.
+/// Much quicker than SipHash on the small tuple and string keys the days use, but offers no
+/// protection against keys chosen to collide so keep it away from untrusted input.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    #[inline]
+    fn add_to_hash(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in chunks.by_ref() {
+            self.add_to_hash(u64::from_le_bytes(
+                chunk.try_into().expect("chunk is 8 bytes"),
+            ));
+        }
+        let mut rest = chunks.remainder();
+        if rest.len() >= 4 {
+            self.add_to_hash(u32::from_le_bytes(rest[..4].try_into().expect("4 bytes")) as u64);
+            rest = &rest[4..];
+        }
+        if rest.len() >= 2 {
+            self.add_to_hash(u16::from_le_bytes(rest[..2].try_into().expect("2 bytes")) as u64);
+            rest = &rest[2..];
+        }
+        if let Some(byte) = rest.first() {
+            self.add_to_hash(*byte as u64);
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add_to_hash(i);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
+/// A `HashMap` using `FxHasher`. Build one with `FastMap::default()` rather than `new`.
+pub type FastMap<K, V> = HashMap<K, V, FxBuildHasher>;
+pub type FastSet<T> = HashSet<T, FxBuildHasher>;
+
+#[cfg(test)]
+mod test_hash {
+    use std::hash::{BuildHasher, Hash, Hasher};
+
+    use crate::hash::{FastMap, FastSet, FxBuildHasher, FxHasher};
+
+    fn hash_of(value: impl Hash) -> u64 {
+        FxBuildHasher::default().hash_one(value)
+    }
+
+    #[test]
+    fn test_hasher_is_deterministic_and_spreads() {
+        assert_eq!(hash_of(("abc", 3usize)), hash_of(("abc", 3usize)));
+        assert_ne!(hash_of(("abc", 3usize)), hash_of(("abc", 4usize)));
+        assert_ne!(hash_of((1usize, 2usize)), hash_of((2usize, 1usize)));
+
+        // Every tail length goes through a different branch of `write`
+        let hashes = (0..=17)
+            .map(|len| {
+                let mut hasher = FxHasher::default();
+                hasher.write(&b"abcdefghijklmnopq"[..len]);
+                hasher.finish()
+            })
+            .collect::<FastSet<_>>();
+        assert_eq!(18, hashes.len());
+    }
+
+    #[test]
+    fn test_fast_collections() {
+        let mut map: FastMap<String, usize> = FastMap::default();
+        for (idx, wire) in ["x00", "y00", "z00", "x00"].into_iter().enumerate() {
+            map.insert(wire.to_string(), idx);
+        }
+        assert_eq!(3, map.len());
+        assert_eq!(Some(&3), map.get("x00"));
+    }
+}
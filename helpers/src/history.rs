@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::params::Params;
+use crate::runner::{PartReport, Status};
+
+/// One line of the history file. Only parts that actually produced an answer are recorded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryRecord {
+    // Seconds since the unix epoch
+    pub timestamp: u64,
+    // Everything recorded before years were tracked was 2024
+    #[serde(default = "first_year")]
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub variant: String,
+    pub input_hash: String,
+    // Missing from records written before params were tracked
+    #[serde(default)]
+    pub params: Params,
+    pub answer: String,
+    pub status: Status,
+    #[serde(alias = "parse_ms")]
+    pub read_ms: f64,
+    pub solve_ms: f64,
+}
+
+impl HistoryRecord {
+    pub fn from_report(timestamp: u64, report: &PartReport) -> Option<Self> {
+        // Streamed timings include the reading and cached ones skip solving, so either would look
+        // out of place next to the rest
+        if report.streamed || report.cached {
+            return None;
+        }
+        Some(HistoryRecord {
+            timestamp,
+            year: report.year,
+            day: report.day,
+            part: report.part,
+            variant: report.variant.clone(),
+            input_hash: report.input_hash.clone()?,
+            params: report.params.clone(),
+            answer: report.answer.clone()?,
+            status: report.status,
+            read_ms: report.read_ms,
+            solve_ms: report.solve_ms,
+        })
+    }
+
+    // Runs are only comparable when they solved the same thing the same way
+    fn key(&self) -> RecordKey {
+        (
+            self.year,
+            self.day,
+            self.part,
+            self.variant.clone(),
+            self.input_hash.clone(),
+            self.params.clone(),
+        )
+    }
+}
+
+type RecordKey = (u32, u32, u8, String, String, Params);
+
+fn first_year() -> u32 {
+    2024
+}
+
+pub fn default_path(root: &Path) -> PathBuf {
+    root.join(".aoc").join("history.jsonl")
+}
+
+pub fn append(path: &Path, records: &[HistoryRecord]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for record in records {
+        let line = serde_json::to_string(record).expect("record is always serializable");
+        writeln!(file, "{line}")?;
+    }
+    Ok(())
+}
+
+/// Reads every record, skipping lines that don't parse so one bad write doesn't lose the rest.
+/// A missing file is an empty history.
+pub fn load(path: &Path) -> io::Result<Vec<HistoryRecord>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    Ok(contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    pub record: HistoryRecord,
+    pub best_solve_ms: f64,
+    // How much slower than the best run for the same input, e.g. 0.25 for 25% slower
+    pub slowdown: f64,
+    pub flagged: bool,
+}
+
+/// Pairs every record with the best solve time recorded for the same puzzle, part, variant, input
+/// and params, flagging the ones more than `threshold` (a fraction, 0.2 = 20%) slower than it.
+pub fn compare_to_best(records: Vec<HistoryRecord>, threshold: f64) -> Vec<HistoryEntry> {
+    let mut best: HashMap<RecordKey, f64> = HashMap::new();
+    for record in records.iter() {
+        let best_ms = best.entry(record.key()).or_insert(f64::MAX);
+        *best_ms = best_ms.min(record.solve_ms);
+    }
+
+    records
+        .into_iter()
+        .map(|record| {
+            let best_solve_ms = best[&record.key()];
+            let slowdown = if best_solve_ms > 0.0 {
+                record.solve_ms / best_solve_ms - 1.0
+            } else {
+                0.0
+            };
+            HistoryEntry {
+                flagged: slowdown > threshold,
+                record,
+                best_solve_ms,
+                slowdown,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_history {
+    use std::fs;
+
+    use crate::history::{append, compare_to_best, load, HistoryRecord};
+    use crate::params::{Param, Params};
+    use crate::runner::Status;
+
+    fn record(timestamp: u64, input_hash: &str, solve_ms: f64) -> HistoryRecord {
+        HistoryRecord {
+            timestamp,
+            year: 2024,
+            day: 6,
+            part: 2,
+            variant: "default".to_string(),
+            input_hash: input_hash.to_string(),
+            params: Params::default(),
+            answer: "1234".to_string(),
+            status: Status::Unverified,
+            read_ms: 0.1,
+            solve_ms,
+        }
+    }
+
+    #[test]
+    fn test_append_and_load() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-history-{}", std::process::id()))
+            .join("history.jsonl");
+        append(&path, &[record(1, "aa", 10.0)]).unwrap();
+        append(&path, &[record(2, "aa", 12.0), record(3, "bb", 5.0)]).unwrap();
+        // A torn write shouldn't hide the records around it
+        fs::write(
+            &path,
+            fs::read_to_string(&path).unwrap() + "{\"timestamp\":\n",
+        )
+        .unwrap();
+
+        let loaded = load(&path).unwrap();
+        assert_eq!(3, loaded.len());
+        assert_eq!(record(3, "bb", 5.0), loaded[2]);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert!(load(&path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_records_without_year_are_2024() {
+        let line = r#"{"timestamp":1,"day":6,"part":2,"variant":"default","input_hash":"aa","answer":"1234","status":"unverified","read_ms":0.1,"solve_ms":10.0}"#;
+        let parsed: HistoryRecord = serde_json::from_str(line).unwrap();
+        assert_eq!(record(1, "aa", 10.0), parsed);
+    }
+
+    #[test]
+    fn test_compare_to_best_is_per_input() {
+        let entries = compare_to_best(
+            vec![
+                record(1, "aa", 10.0),
+                record(2, "aa", 13.0),
+                record(3, "bb", 20.0),
+                record(4, "aa", 11.0),
+            ],
+            0.2,
+        );
+        let flagged = entries
+            .iter()
+            .map(|entry| entry.flagged)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![false, true, false, false], flagged);
+        assert_eq!(20.0, entries[2].best_solve_ms);
+        assert!((entries[1].slowdown - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compare_to_best_is_per_params() {
+        let declared = [Param::int("time_to_save", 100)];
+        let mut overridden = Params::defaults(&declared);
+        overridden
+            .apply(&[("time_to_save".to_string(), "50".to_string())])
+            .unwrap();
+        let with_params = |timestamp, params: &Params, solve_ms| HistoryRecord {
+            params: params.clone(),
+            ..record(timestamp, "aa", solve_ms)
+        };
+
+        let entries = compare_to_best(
+            vec![
+                with_params(1, &Params::defaults(&declared), 10.0),
+                with_params(2, &overridden, 30.0),
+                with_params(3, &overridden, 31.0),
+            ],
+            0.2,
+        );
+        assert!(entries.iter().all(|entry| !entry.flagged));
+        assert_eq!(30.0, entries[2].best_solve_ms);
+    }
+}
@@ -0,0 +1,261 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{self, Child, Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::cache::{Cache, Fingerprints};
+use crate::params::parse_assignment;
+use crate::runner::{
+    input_label, run_part_with, stream_part, PartReport, Registry, Solver, Status,
+};
+use crate::workspace_root;
+
+/// The hidden `aoc` subcommand a child runs, handed straight to `run_child`.
+pub const CHILD_COMMAND: &str = "run-isolated-part";
+
+// How often a child with a time limit is checked on
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+// What Rust's default allocation error handler prints right before it aborts
+const OOM_MESSAGE: &str = "memory allocation of";
+
+// Tells apart the report files of children running at the same time, like the server's
+static NEXT_CHILD: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Limits {
+    // Wall-clock time the part gets before the child is killed
+    pub time: Option<Duration>,
+    // Cap on the child's address space in bytes, only enforced on linux
+    pub memory: Option<u64>,
+}
+
+/// How the child should run its part.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChildOptions {
+    // Use `stream_part` instead of reading the input first
+    pub stream: bool,
+    // Check and fill the workspace's result cache, only for parts that aren't streamed
+    pub cache: bool,
+    pub limits: Limits,
+}
+
+/// Like `run_part_with`, or `stream_part` when streaming, but runs the part in a child copy of
+/// the current executable so a part that hangs, runs out of memory or aborts only takes itself
+/// down. The executable has to hand `CHILD_COMMAND` on to `run_child`.
+pub fn run_isolated(
+    solver: &Solver,
+    part: u8,
+    input_path: &Path,
+    overrides: &[(String, String)],
+    options: ChildOptions,
+) -> Option<PartReport> {
+    let limits = options.limits;
+    solver.part(part)?;
+    let report_path = env::temp_dir().join(format!(
+        "aoc-part-{}-{}-{}-{}-{part}.json",
+        process::id(),
+        NEXT_CHILD.fetch_add(1, Ordering::Relaxed),
+        solver.year,
+        solver.day
+    ));
+    let _ = fs::remove_file(&report_path);
+
+    let mut command = child_command(solver, part, input_path, &report_path, overrides, options);
+    if let Some(bytes) = limits.memory {
+        limit_memory(&mut command, bytes);
+    }
+    let started = Instant::now();
+    let failed = |status, error: String| {
+        let mut report = PartReport::not_run(solver, part, input_label(input_path), status);
+        report.error = Some(error);
+        report.solve_ms = started.elapsed().as_secs_f64() * 1000.0;
+        Some(report)
+    };
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(err) => return failed(Status::Crashed, format!("couldn't start the child: {err}")),
+    };
+
+    // Read on its own thread so a chatty part can't fill the pipe and stall
+    let mut stderr = child.stderr.take().expect("stderr is piped");
+    let stderr = thread::spawn(move || {
+        let mut text = String::new();
+        let _ = stderr.read_to_string(&mut text);
+        text
+    });
+    let exit = wait(&mut child, limits.time);
+    let stderr = stderr.join().unwrap_or_default();
+    eprint!("{stderr}");
+
+    let report = fs::read_to_string(&report_path)
+        .ok()
+        .and_then(|json| serde_json::from_str::<PartReport>(&json).ok());
+    let _ = fs::remove_file(&report_path);
+    match (exit, report) {
+        (Ok(Some(_)), Some(report)) => Some(report),
+        (Ok(None), _) => failed(
+            Status::TimedOut,
+            format!(
+                "didn't finish within {:.3} s",
+                limits.time.unwrap_or_default().as_secs_f64()
+            ),
+        ),
+        (Ok(Some(_)), None) if stderr.contains(OOM_MESSAGE) => failed(
+            Status::OutOfMemory,
+            format!(
+                "ran out of memory with a limit of {} MB",
+                limits.memory.unwrap_or_default() / (1024 * 1024)
+            ),
+        ),
+        (Ok(Some(exit)), None) => failed(Status::Crashed, format!("child died with {exit}")),
+        (Err(err), _) => failed(Status::Crashed, format!("lost track of the child: {err}")),
+    }
+}
+
+fn child_command(
+    solver: &Solver,
+    part: u8,
+    input_path: &Path,
+    report_path: &Path,
+    overrides: &[(String, String)],
+    options: ChildOptions,
+) -> Command {
+    let exe = env::current_exe().expect("should be able to find the running executable");
+    let mut command = Command::new(exe);
+    command
+        .arg(CHILD_COMMAND)
+        .args([
+            solver.year.to_string(),
+            solver.day.to_string(),
+            part.to_string(),
+        ])
+        .arg(input_path)
+        .arg(report_path);
+    if options.stream {
+        command.arg("--stream");
+    }
+    if options.cache {
+        command.arg("--cache");
+    }
+    for (key, value) in overrides {
+        command.arg("--param").arg(format!("{key}={value}"));
+    }
+    // The part's own output still goes straight to the terminal
+    command.stdin(Stdio::null()).stderr(Stdio::piped());
+    command
+}
+
+#[cfg(target_os = "linux")]
+fn limit_memory(command: &mut Command, bytes: u64) {
+    use std::os::unix::process::CommandExt;
+
+    let limit = libc::rlimit {
+        rlim_cur: bytes,
+        rlim_max: bytes,
+    };
+    // SAFETY: setrlimit is async-signal-safe and nothing else happens between fork and exec
+    unsafe {
+        command.pre_exec(move || {
+            if libc::setrlimit(libc::RLIMIT_AS, &limit) == 0 {
+                Ok(())
+            } else {
+                Err(io::Error::last_os_error())
+            }
+        });
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn limit_memory(_command: &mut Command, _bytes: u64) {
+    println!("memory limits only work on linux, running without one");
+}
+
+// Waits for the child to exit, killing it once `limit` is up. `None` means it had to be killed
+fn wait(child: &mut Child, limit: Option<Duration>) -> io::Result<Option<ExitStatus>> {
+    let Some(limit) = limit else {
+        return child.wait().map(Some);
+    };
+    let deadline = Instant::now() + limit;
+    loop {
+        if let Some(exit) = child.try_wait()? {
+            return Ok(Some(exit));
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// The child's side of `run_isolated`: runs the part described by `args` in this process and
+/// writes its report where the parent will look for it. `fingerprints` are the ones the parent
+/// caches with, as both are the same binary.
+pub fn run_child(registry: &Registry, fingerprints: Fingerprints, args: &[String]) {
+    let [year, day, part, input_path, report_path, flags @ ..] = args else {
+        panic!("{CHILD_COMMAND} needs a year, day, part, input and report path");
+    };
+    let number = |arg: &String| arg.parse::<u32>().expect("has to be a number");
+    let solver = registry
+        .get(number(year), number(day))
+        .expect("the parent only runs registered days");
+    let part = number(part) as u8;
+
+    let mut stream = false;
+    let mut cache = None;
+    let mut overrides = Vec::new();
+    let mut flags = flags.iter();
+    while let Some(flag) = flags.next() {
+        match flag.as_str() {
+            "--stream" => stream = true,
+            "--cache" => cache = Some(Cache::new(&workspace_root(), fingerprints)),
+            "--param" => overrides.push(
+                parse_assignment(flags.next().expect("--param needs a value"))
+                    .expect("the parent already checked the assignment"),
+            ),
+            other => panic!("unknown flag {other}"),
+        }
+    }
+
+    let input_path = PathBuf::from(input_path);
+    let report = if stream {
+        stream_part(solver, part, &input_path, &overrides)
+    } else {
+        run_part_with(solver, part, &input_path, &overrides, cache.as_ref())
+    }
+    .expect("the parent checked the part");
+    fs::write(
+        report_path,
+        serde_json::to_string(&report).expect("report is always serializable"),
+    )
+    .expect("should be able to write the report");
+}
+
+#[cfg(test)]
+mod test_isolate {
+    use std::process::Command;
+    use std::time::{Duration, Instant};
+
+    use crate::isolate::wait;
+
+    #[test]
+    fn test_wait_kills_after_limit() {
+        let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+        let started = Instant::now();
+        assert_eq!(
+            None,
+            wait(&mut child, Some(Duration::from_millis(50))).unwrap()
+        );
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        let mut child = Command::new("true").spawn().unwrap();
+        let exit = wait(&mut child, Some(Duration::from_secs(5))).unwrap();
+        assert!(exit.is_some_and(|exit| exit.success()));
+    }
+}
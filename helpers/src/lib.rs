@@ -1,7 +1,44 @@
 use std::collections::HashMap;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 use std::hash::Hash;
-use std::{env, fs, path::Path};
+use std::io::BufRead;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use std::{env, path::Path};
+
+use alloc::AllocStats;
+
+pub mod alloc;
+pub mod batch;
+pub mod bit_grid;
+pub mod bit_vec;
+pub mod cache;
+pub mod cycle;
+pub mod dot;
+pub mod hash;
+pub mod history;
+pub mod isolate;
+pub mod linear;
+pub mod memo;
+pub mod meta;
+pub mod num;
+pub mod params;
+pub mod progress;
+pub mod rational;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod serve;
+pub mod sim;
+pub mod span_set;
+pub mod stream;
+pub mod trie;
+pub mod union_find;
+
+pub use bit_grid::BitGrid;
+pub use bit_vec::BitVec;
+pub use rational::Rational;
+pub use trie::{AhoCorasick, Trie};
 
 pub fn read_grid(contents: String) -> Vec<Vec<char>> {
     contents
@@ -10,44 +47,101 @@ pub fn read_grid(contents: String) -> Vec<Vec<char>> {
         .collect::<Vec<_>>()
 }
 
+pub fn workspace_root() -> PathBuf {
+    let output = std::process::Command::new(env!("CARGO"))
+        .arg("locate-project")
+        .arg("--workspace")
+        .arg("--message-format=plain")
+        .output()
+        .unwrap()
+        .stdout;
+    let cargo_path = Path::new(std::str::from_utf8(&output).unwrap().trim());
+    cargo_path.parent().unwrap().to_path_buf()
+}
+
+/// What a puzzle part produces. Kept as a string so numeric and text answers compare the same way.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Answer(String);
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($($kind:ty),*) => {
+        $(
+            impl From<$kind> for Answer {
+                fn from(value: $kind) -> Self {
+                    Answer(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(i32, i64, i128, isize, u32, u64, u128, usize, String, &str);
+
 pub trait Puzzle {
-    fn puzzle_1(contents: String);
-    fn puzzle_2(contents: String);
-
-    fn run() {
-        let args: Vec<String> = env::args().collect();
-
-        let puzzle_num: &i64 = &args[1].parse().unwrap();
-        let output = std::process::Command::new(env!("CARGO"))
-            .arg("locate-project")
-            .arg("--workspace")
-            .arg("--message-format=plain")
-            .output()
-            .unwrap()
-            .stdout;
-        let cargo_path = Path::new(std::str::from_utf8(&output).unwrap().trim());
-        let file_path = cargo_path
-            .parent()
-            .unwrap()
-            .to_path_buf()
-            .join("inputs")
-            .join(&args[2]);
-
-        println!("reading: {:?}", file_path);
-
-        let contents =
-            fs::read_to_string(file_path).expect("Should have been able to read the file");
-
-        if *puzzle_num == 1 as i64 {
-            Self::puzzle_1(contents);
-        } else if *puzzle_num == 2 as i64 {
-            Self::puzzle_2(contents);
-        } else {
-            println!("bad puzzle num");
+    const YEAR: u32;
+    const DAY: u32;
+    // Lets a day keep more than one implementation around and tell their runs apart
+    const VARIANT: &'static str = "default";
+    // Values that aren't in the input, read with `params::int` and friends inside the parts
+    const PARAMS: &'static [params::Param] = &[];
+    // What `aoc list` shows, so earlier days that did something similar are easy to find. Helpers
+    // are named by the `helpers` module they come from
+    const TITLE: &'static str = "";
+    const TAGS: &'static [meta::Tag] = &[];
+    const HELPERS: &'static [&'static str] = &[];
+    // The approach in a line, and how it scales
+    const NOTES: &'static str = "";
+
+    fn puzzle_1(contents: String) -> Answer;
+    fn puzzle_2(contents: String) -> Answer;
+
+    // Parts that read their input as they go, so inputs too big to hold still run. Days that can
+    // work a line or chunk at a time override these, the rest read it all and run as normal
+    fn stream_1(input: &mut dyn BufRead) -> Answer {
+        Self::puzzle_1(stream::read_all(input))
+    }
+
+    fn stream_2(input: &mut dyn BufRead) -> Answer {
+        Self::puzzle_2(stream::read_all(input))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StageReport {
+    pub name: &'static str,
+    pub elapsed: Duration,
+    // Only present when the counting allocator is installed
+    pub allocs: Option<AllocStats>,
+}
+
+impl std::fmt::Display for StageReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] took {:?}", self.name, self.elapsed)?;
+        if let Some(allocs) = &self.allocs {
+            write!(f, " ({allocs})")?;
         }
+        Ok(())
     }
 }
 
+/// Times `f` and, if enabled, counts its allocations.
+pub fn measure_stage<T>(name: &'static str, f: impl FnOnce() -> T) -> (T, StageReport) {
+    let start = Instant::now();
+    let (result, allocs) = alloc::measure(f);
+    let report = StageReport {
+        name,
+        elapsed: start.elapsed(),
+        allocs: alloc::enabled().then_some(allocs),
+    };
+    (result, report)
+}
+
 #[derive(Eq, PartialEq, Hash, Debug, Clone)]
 enum HeapKind {
     Min,
@@ -0,0 +1,217 @@
+use std::fmt::Display;
+
+use crate::Rational;
+
+/// Every solution is `particular` plus any combination of `directions`, one direction for each
+/// unknown left free.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parametric {
+    pub particular: Vec<Rational>,
+    pub directions: Vec<Vec<Rational>>,
+}
+
+impl Parametric {
+    /// The solution with the free unknowns set to `params`, in the order of `directions`.
+    pub fn at(&self, params: &[Rational]) -> Option<Vec<Rational>> {
+        assert_eq!(
+            self.directions.len(),
+            params.len(),
+            "one param per direction"
+        );
+        let mut result = self.particular.clone();
+        for (direction, param) in self.directions.iter().zip(params) {
+            for (value, step) in result.iter_mut().zip(direction) {
+                *value = value.checked_add(step.checked_mul(*param)?)?;
+            }
+        }
+        Some(result)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solution {
+    Unique(Vec<Rational>),
+    None,
+    Infinite(Parametric),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinearError {
+    // Rows have to be the same length and there has to be a right hand side for each
+    Shape,
+    Overflow,
+}
+
+impl Display for LinearError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinearError::Shape => write!(f, "matrix and right hand side don't fit together"),
+            LinearError::Overflow => write!(f, "overflowed while eliminating"),
+        }
+    }
+}
+
+fn checked<T>(value: Option<T>) -> Result<T, LinearError> {
+    value.ok_or(LinearError::Overflow)
+}
+
+/// Solves `matrix * x = rhs` exactly with Gauss-Jordan elimination. Usually square, but any
+/// number of equations works.
+pub fn solve(matrix: &[Vec<Rational>], rhs: &[Rational]) -> Result<Solution, LinearError> {
+    let unknowns = matrix.first().map_or(0, |row| row.len());
+    if matrix.len() != rhs.len() || matrix.iter().any(|row| row.len() != unknowns) {
+        return Err(LinearError::Shape);
+    }
+
+    // Each row carries its right hand side as a last column
+    let mut rows = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, value)| {
+            let mut row = row.clone();
+            row.push(*value);
+            row
+        })
+        .collect::<Vec<_>>();
+
+    // Reduced row echelon form, remembering which column each pivot row solves for
+    let mut pivots = Vec::new();
+    for col in 0..unknowns {
+        let next = pivots.len();
+        let Some(found) = (next..rows.len()).find(|row| !rows[*row][col].is_zero()) else {
+            continue;
+        };
+        rows.swap(next, found);
+
+        let scale = checked(rows[next][col].checked_recip())?;
+        for value in rows[next].iter_mut() {
+            *value = checked(value.checked_mul(scale))?;
+        }
+        let pivot_row = rows[next].clone();
+        for (idx, row) in rows.iter_mut().enumerate() {
+            let factor = row[col];
+            if idx == next || factor.is_zero() {
+                continue;
+            }
+            for (value, pivot) in row.iter_mut().zip(&pivot_row).skip(col) {
+                let scaled = checked(pivot.checked_mul(factor))?;
+                *value = checked(value.checked_sub(scaled))?;
+            }
+        }
+        pivots.push(col);
+    }
+
+    // Below the pivots every coefficient is 0, so a non-zero right hand side there is 0 = c
+    if rows[pivots.len()..]
+        .iter()
+        .any(|row| !row[unknowns].is_zero())
+    {
+        return Ok(Solution::None);
+    }
+
+    let mut particular = vec![Rational::ZERO; unknowns];
+    for (row, col) in pivots.iter().enumerate() {
+        particular[*col] = rows[row][unknowns];
+    }
+    if pivots.len() == unknowns {
+        return Ok(Solution::Unique(particular));
+    }
+
+    // Raising a free unknown by one moves each pivot unknown against its coefficient
+    let mut directions = Vec::new();
+    for free in (0..unknowns).filter(|col| !pivots.contains(col)) {
+        let mut direction = vec![Rational::ZERO; unknowns];
+        direction[free] = Rational::ONE;
+        for (row, col) in pivots.iter().enumerate() {
+            direction[*col] = checked(rows[row][free].checked_neg())?;
+        }
+        directions.push(direction);
+    }
+    Ok(Solution::Infinite(Parametric {
+        particular,
+        directions,
+    }))
+}
+
+#[cfg(test)]
+mod test_linear {
+    use crate::linear::{solve, LinearError, Solution};
+    use crate::Rational;
+
+    fn matrix(rows: &[&[i64]]) -> Vec<Vec<Rational>> {
+        rows.iter()
+            .map(|row| row.iter().map(|value| Rational::from(*value)).collect())
+            .collect()
+    }
+
+    fn vector(values: &[i64]) -> Vec<Rational> {
+        values.iter().map(|value| Rational::from(*value)).collect()
+    }
+
+    // matrix * x, to check solutions against
+    fn apply(matrix: &[Vec<Rational>], x: &[Rational]) -> Vec<Rational> {
+        matrix
+            .iter()
+            .map(|row| {
+                row.iter().zip(x).fold(Rational::ZERO, |acc, (a, b)| {
+                    acc.checked_add(a.checked_mul(*b).unwrap()).unwrap()
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unique() {
+        // The Day 13 example's first machine
+        let buttons = matrix(&[&[94, 22], &[34, 67]]);
+        assert_eq!(
+            Ok(Solution::Unique(vector(&[80, 40]))),
+            solve(&buttons, &vector(&[8400, 5400]))
+        );
+
+        let system = matrix(&[&[0, 2, 1], &[1, -1, 0], &[3, 0, 4]]);
+        let Ok(Solution::Unique(x)) = solve(&system, &vector(&[1, 2, 3])) else {
+            panic!("should have one solution");
+        };
+        assert_eq!(vector(&[1, 2, 3]), apply(&system, &x));
+        assert_eq!(Rational::new(7, 5), Some(x[1]));
+    }
+
+    #[test]
+    fn test_none() {
+        let parallel = matrix(&[&[1, 2], &[2, 4]]);
+        assert_eq!(Ok(Solution::None), solve(&parallel, &vector(&[3, 7])));
+    }
+
+    #[test]
+    fn test_infinite() {
+        let system = matrix(&[&[1, 2, 3], &[2, 4, 6], &[1, 0, 1]]);
+        let rhs = vector(&[6, 12, 2]);
+        let Ok(Solution::Infinite(parametric)) = solve(&system, &rhs) else {
+            panic!("should have a line of solutions");
+        };
+        assert_eq!(1, parametric.directions.len());
+        for param in -3..=3i64 {
+            let x = parametric.at(&[Rational::from(param)]).unwrap();
+            assert_eq!(rhs, apply(&system, &x));
+        }
+
+        // Every unknown free
+        let Ok(Solution::Infinite(parametric)) = solve(&matrix(&[&[0, 0]]), &vector(&[0])) else {
+            panic!("0 = 0 is always true");
+        };
+        assert_eq!(2, parametric.directions.len());
+    }
+
+    #[test]
+    fn test_shape() {
+        assert_eq!(
+            Err(LinearError::Shape),
+            solve(&matrix(&[&[1, 2], &[3]]), &vector(&[1, 2]))
+        );
+        assert_eq!(
+            Err(LinearError::Shape),
+            solve(&matrix(&[&[1, 2]]), &vector(&[1, 2]))
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use std::borrow::Borrow;
+use std::fmt::Display;
+use std::hash::Hash;
+
+use crate::hash::FastMap;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+    // Entries thrown away to stay under the size bound
+    pub evictions: usize,
+    pub entries: usize,
+}
+
+impl Display for MemoStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lookups = self.hits + self.misses;
+        let hit_rate = if lookups > 0 {
+            self.hits as f64 / lookups as f64 * 100.0
+        } else {
+            0.0
+        };
+        write!(
+            f,
+            "hits: {}, misses: {} ({hit_rate:.1}% hit rate), entries: {}, evicted: {}",
+            self.hits, self.misses, self.entries, self.evictions
+        )
+    }
+}
+
+/// A cache of computed values that counts its hits and misses. Lookups go through `Borrow` so
+/// a `Memo<String, _>` can be checked with a `&str` without allocating.
+pub struct Memo<K, V> {
+    cache: FastMap<K, V>,
+    max_entries: Option<usize>,
+    stats: MemoStats,
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: FastMap::default(),
+            max_entries: None,
+            stats: MemoStats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo::default()
+    }
+
+    /// A memo that never holds more than `max_entries`. Once full it's emptied and starts over,
+    /// which keeps memory bounded without having to track what was used recently.
+    pub fn bounded(max_entries: usize) -> Self {
+        Memo {
+            max_entries: Some(max_entries),
+            ..Memo::default()
+        }
+    }
+
+    pub fn get<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let value = self.cache.get(key).cloned();
+        match value {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1,
+        }
+        value
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        if self
+            .max_entries
+            .is_some_and(|max_entries| self.cache.len() >= max_entries)
+        {
+            self.stats.evictions += self.cache.len();
+            self.cache.clear();
+        }
+        if self.max_entries != Some(0) {
+            self.cache.insert(key, value);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            entries: self.cache.len(),
+            ..self.stats
+        }
+    }
+}
+
+type Body<'f, K, V, Q> = dyn Fn(&mut Recurse<'_, K, V, Q>, &Q) -> V + 'f;
+
+/// Handed to a `Memoized` function's body so it can call itself through the cache.
+pub struct Recurse<'a, K, V, Q: ?Sized> {
+    memo: &'a mut Memo<K, V>,
+    body: &'a Body<'a, K, V, Q>,
+}
+
+impl<K, V, Q> Recurse<'_, K, V, Q>
+where
+    K: Hash + Eq + Borrow<Q>,
+    V: Clone,
+    Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+{
+    pub fn call(&mut self, key: &Q) -> V {
+        if let Some(value) = self.memo.get(key) {
+            return value;
+        }
+        let value = (self.body)(self, key);
+        self.memo.insert(key.to_owned(), value.clone());
+        value
+    }
+}
+
+/// A recursive function that owns its cache. The body gets a `Recurse` to make its recursive
+/// calls through, so it reads like the plain definition:
+///
+/// ```
+/// use helpers::memo::Memoized;
+///
+/// let mut fib = Memoized::new(|fib, n: &u64| match *n {
+///     0 | 1 => *n,
+///     n => fib.call(&(n - 1)) + fib.call(&(n - 2)),
+/// });
+/// assert_eq!(12_586_269_025, fib.call(&50));
+/// ```
+pub struct Memoized<'f, K, V, Q: ?Sized> {
+    memo: Memo<K, V>,
+    body: Box<Body<'f, K, V, Q>>,
+}
+
+impl<'f, K, V, Q> Memoized<'f, K, V, Q>
+where
+    K: Hash + Eq + Borrow<Q>,
+    V: Clone,
+    Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+{
+    pub fn new(body: impl Fn(&mut Recurse<'_, K, V, Q>, &Q) -> V + 'f) -> Self {
+        Memoized {
+            memo: Memo::new(),
+            body: Box::new(body),
+        }
+    }
+
+    pub fn bounded(
+        max_entries: usize,
+        body: impl Fn(&mut Recurse<'_, K, V, Q>, &Q) -> V + 'f,
+    ) -> Self {
+        Memoized {
+            memo: Memo::bounded(max_entries),
+            body: Box::new(body),
+        }
+    }
+
+    pub fn call(&mut self, key: &Q) -> V {
+        Recurse {
+            memo: &mut self.memo,
+            body: &*self.body,
+        }
+        .call(key)
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.memo.stats()
+    }
+}
+
+#[cfg(test)]
+mod test_memo {
+    use std::cell::Cell;
+
+    use crate::memo::{Memo, Memoized};
+
+    #[test]
+    fn test_memo_borrowed_lookup_and_stats() {
+        let mut memo: Memo<String, usize> = Memo::new();
+        assert_eq!(None, memo.get("abc"));
+        memo.insert("abc".to_string(), 3);
+        assert_eq!(Some(3), memo.get("abc"));
+
+        let stats = memo.stats();
+        assert_eq!((1, 1, 1), (stats.hits, stats.misses, stats.entries));
+        assert_eq!(
+            "hits: 1, misses: 1 (50.0% hit rate), entries: 1, evicted: 0",
+            stats.to_string()
+        );
+    }
+
+    #[test]
+    fn test_memo_bounded() {
+        let mut memo = Memo::bounded(2);
+        for key in 0..5 {
+            memo.insert(key, key * 2);
+        }
+        assert!(memo.len() <= 2);
+        assert_eq!(Some(8), memo.get(&4));
+        assert_eq!(4, memo.stats().evictions);
+    }
+
+    #[test]
+    fn test_memoized_recursion_calls_body_once_per_key() {
+        let calls = Cell::new(0);
+        let mut ways = Memoized::new(|ways, rest: &str| {
+            calls.set(calls.get() + 1);
+            if rest.is_empty() {
+                return 1u64;
+            }
+            ["a", "ab", "b"]
+                .iter()
+                .filter_map(|towel| rest.strip_prefix(towel))
+                .map(|rest| ways.call(rest))
+                .sum()
+        });
+        assert_eq!(4, ways.call("abab"));
+        assert_eq!(2, ways.call("bab"));
+        // "abab", "bab", "ab", "b" and ""
+        assert_eq!(5, calls.get());
+        assert!(ways.stats().hits > 0);
+    }
+
+    #[test]
+    fn test_memoized_bounded_still_answers() {
+        let mut fib = Memoized::bounded(8, |fib, n: &u64| match *n {
+            0 | 1 => *n,
+            n => fib.call(&(n - 1)) + fib.call(&(n - 2)),
+        });
+        assert_eq!(832_040, fib.call(&30));
+        assert!(fib.stats().entries <= 8);
+    }
+}
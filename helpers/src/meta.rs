@@ -0,0 +1,183 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::runner::Solver;
+
+/// The kind of technique a puzzle called for, so `aoc list --tag` can dig up earlier days that
+/// solved something similar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Tag {
+    Grid,
+    Graph,
+    Dp,
+    Simulation,
+    Vm,
+    Parsing,
+    Math,
+}
+
+impl Tag {
+    pub const ALL: [Tag; 7] = [
+        Tag::Grid,
+        Tag::Graph,
+        Tag::Dp,
+        Tag::Simulation,
+        Tag::Vm,
+        Tag::Parsing,
+        Tag::Math,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tag::Grid => "grid",
+            Tag::Graph => "graph",
+            Tag::Dp => "dp",
+            Tag::Simulation => "simulation",
+            Tag::Vm => "vm",
+            Tag::Parsing => "parsing",
+            Tag::Math => "math",
+        }
+    }
+}
+
+impl Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Tag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Tag::ALL
+            .into_iter()
+            .find(|tag| tag.name() == s)
+            .ok_or_else(|| {
+                let names = Tag::ALL.map(|tag| tag.name());
+                format!("no tag named {s}, try one of {}", names.join(", "))
+            })
+    }
+}
+
+/// Everything known about a solver apart from the solver itself, as exported by `aoc list`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SolverInfo {
+    pub year: u32,
+    pub day: u32,
+    pub title: String,
+    pub variant: String,
+    pub tags: Vec<Tag>,
+    pub helpers: Vec<String>,
+    pub notes: String,
+    pub params: Vec<String>,
+}
+
+impl SolverInfo {
+    pub fn of(solver: &Solver) -> Self {
+        SolverInfo {
+            year: solver.year,
+            day: solver.day,
+            title: solver.title.to_string(),
+            variant: solver.variant.to_string(),
+            tags: solver.tags.to_vec(),
+            helpers: solver.helpers.iter().map(|name| name.to_string()).collect(),
+            notes: solver.notes.to_string(),
+            params: solver
+                .params
+                .iter()
+                .map(|param| param.name.to_string())
+                .collect(),
+        }
+    }
+
+    pub fn has_tag(&self, tag: Tag) -> bool {
+        self.tags.contains(&tag)
+    }
+}
+
+/// `infos` as a pretty-printed JSON array.
+pub fn to_json(infos: &[SolverInfo]) -> String {
+    serde_json::to_string_pretty(infos).expect("solver info is always serializable")
+}
+
+impl Display for SolverInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let title = if self.title.is_empty() {
+            "untitled"
+        } else {
+            &self.title
+        };
+        let tags = self.tags.iter().map(Tag::name).collect::<Vec<_>>();
+        write!(
+            f,
+            "{} day {:>2}  {title} [{}]",
+            self.year,
+            self.day,
+            tags.join(", ")
+        )?;
+        if !self.helpers.is_empty() {
+            write!(f, "\n  helpers: {}", self.helpers.join(", "))?;
+        }
+        if !self.notes.is_empty() {
+            write!(f, "\n  {}", self.notes)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_meta {
+    use crate::meta::{to_json, SolverInfo, Tag};
+    use crate::params::Param;
+    use crate::runner::Solver;
+    use crate::{Answer, Puzzle};
+
+    struct Maze;
+
+    impl Puzzle for Maze {
+        const YEAR: u32 = 2015;
+        const DAY: u32 = 96;
+        const TITLE: &'static str = "Some Maze";
+        const TAGS: &'static [Tag] = &[Tag::Grid, Tag::Graph];
+        const HELPERS: &'static [&'static str] = &["read_grid"];
+        const NOTES: &'static str = "BFS from the start";
+        const PARAMS: &'static [Param] = &[Param::int("size", 7)];
+
+        fn puzzle_1(_contents: String) -> Answer {
+            0.into()
+        }
+
+        fn puzzle_2(_contents: String) -> Answer {
+            0.into()
+        }
+    }
+
+    #[test]
+    fn test_tag_names_round_trip() {
+        for tag in Tag::ALL {
+            assert_eq!(Ok(tag), tag.name().parse());
+        }
+        assert!("graphs".parse::<Tag>().is_err());
+    }
+
+    #[test]
+    fn test_info() {
+        let info = SolverInfo::of(&Solver::of::<Maze>());
+        assert!(info.has_tag(Tag::Graph) && !info.has_tag(Tag::Dp));
+        assert_eq!(vec!["size"], info.params);
+        assert_eq!(
+            "2015 day 96  Some Maze [grid, graph]\n  helpers: read_grid\n  BFS from the start",
+            info.to_string()
+        );
+        let json = to_json(std::slice::from_ref(&info));
+        assert!(json.contains("\"tags\": [\n      \"grid\",\n      \"graph\"\n    ]"));
+        assert_eq!(
+            vec![info],
+            serde_json::from_str::<Vec<SolverInfo>>(&json).unwrap()
+        );
+    }
+}
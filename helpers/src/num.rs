@@ -0,0 +1,343 @@
+use std::fmt::{Debug, Display};
+
+/// The integer operations the number theory helpers are written against. Everything is checked
+/// so an overflow comes back as `None` instead of wrapping or panicking.
+pub trait Int: Copy + Ord + Debug + Display {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    fn checked_rem_euclid(self, other: Self) -> Option<Self>;
+    fn checked_abs(self) -> Option<Self>;
+}
+
+/// Integers that can go negative, which extended Euclid's coefficients need to.
+pub trait Signed: Int {}
+
+macro_rules! impl_int {
+    ($abs:expr => $($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn checked_div(self, other: Self) -> Option<Self> {
+                    <$t>::checked_div(self, other)
+                }
+
+                fn checked_rem(self, other: Self) -> Option<Self> {
+                    <$t>::checked_rem(self, other)
+                }
+
+                fn checked_rem_euclid(self, other: Self) -> Option<Self> {
+                    <$t>::checked_rem_euclid(self, other)
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    $abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(Some => u8, u16, u32, u64, u128, usize);
+impl_int!(Self::checked_abs => i8, i16, i32, i64, i128, isize);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumError {
+    Overflow,
+    // Moduli have to be positive
+    InvalidModulus,
+    NotInvertible,
+    NoSolution,
+}
+
+impl Display for NumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumError::Overflow => write!(f, "overflowed"),
+            NumError::InvalidModulus => write!(f, "moduli have to be positive"),
+            NumError::NotInvertible => {
+                write!(f, "no inverse, the value shares a factor with the modulus")
+            }
+            NumError::NoSolution => write!(f, "the congruences contradict each other"),
+        }
+    }
+}
+
+// Runs a chain of checked operations, any of which overflowing fails the whole thing
+fn checked<T>(chain: impl FnOnce() -> Option<T>) -> Result<T, NumError> {
+    chain().ok_or(NumError::Overflow)
+}
+
+/// Always non-negative, and `gcd(0, 0)` is 0. Only overflows for a signed type's minimum, whose
+/// absolute value doesn't fit.
+pub fn gcd<T: Int>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a.checked_rem(b)?);
+    }
+    a.checked_abs()
+}
+
+/// Always non-negative, and 0 if either side is.
+pub fn lcm<T: Int>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    a.checked_div(gcd(a, b)?)?.checked_mul(b)?.checked_abs()
+}
+
+/// `gcd` along with coefficients where `a * x + b * y == gcd`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtendedGcd<T> {
+    pub gcd: T,
+    pub x: T,
+    pub y: T,
+}
+
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> Option<ExtendedGcd<T>> {
+    // Each pair holds a remainder and the coefficients that make it out of `a` and `b`
+    let (mut old, mut new) = ((a, T::ONE, T::ZERO), (b, T::ZERO, T::ONE));
+    while new.0 != T::ZERO {
+        let quotient = old.0.checked_div(new.0)?;
+        let next = (
+            old.0.checked_sub(quotient.checked_mul(new.0)?)?,
+            old.1.checked_sub(quotient.checked_mul(new.1)?)?,
+            old.2.checked_sub(quotient.checked_mul(new.2)?)?,
+        );
+        (old, new) = (new, next);
+    }
+    if old.0 < T::ZERO {
+        let zero = T::ZERO;
+        old = (
+            zero.checked_sub(old.0)?,
+            zero.checked_sub(old.1)?,
+            zero.checked_sub(old.2)?,
+        );
+    }
+    Some(ExtendedGcd {
+        gcd: old.0,
+        x: old.1,
+        y: old.2,
+    })
+}
+
+/// The `x` in `0..modulus` with `value * x ≡ 1`.
+pub fn mod_inverse<T: Signed>(value: T, modulus: T) -> Result<T, NumError> {
+    if modulus <= T::ZERO {
+        return Err(NumError::InvalidModulus);
+    }
+    let value = checked(|| value.checked_rem_euclid(modulus))?;
+    let extended = checked(|| extended_gcd(value, modulus))?;
+    if extended.gcd != T::ONE {
+        return Err(NumError::NotInvertible);
+    }
+    checked(|| extended.x.checked_rem_euclid(modulus))
+}
+
+/// `base` to the power `exp`, reduced into `0..modulus`. Products are taken before reducing so
+/// `modulus` squared has to fit in `T`.
+pub fn mod_pow<T: Int>(base: T, exp: u64, modulus: T) -> Result<T, NumError> {
+    if modulus <= T::ZERO {
+        return Err(NumError::InvalidModulus);
+    }
+    let mut base = checked(|| base.checked_rem_euclid(modulus))?;
+    let mut result = checked(|| T::ONE.checked_rem_euclid(modulus))?;
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = checked(|| result.checked_mul(base)?.checked_rem(modulus))?;
+        }
+        base = checked(|| base.checked_mul(base)?.checked_rem(modulus))?;
+        exp >>= 1;
+    }
+    Ok(result)
+}
+
+/// `x ≡ residue (mod modulus)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Congruence<T> {
+    pub residue: T,
+    pub modulus: T,
+}
+
+impl<T> Congruence<T> {
+    pub fn new(residue: T, modulus: T) -> Self {
+        Congruence { residue, modulus }
+    }
+}
+
+/// Combines the congruences into the single one every solution satisfies, with the smallest
+/// non-negative solution as its residue and the moduli's lcm as its modulus. The moduli don't
+/// have to be coprime, but where two share a factor their residues have to agree on it.
+pub fn crt<T: Signed>(congruences: &[Congruence<T>]) -> Result<Congruence<T>, NumError> {
+    congruences
+        .iter()
+        .try_fold(Congruence::new(T::ZERO, T::ONE), |acc, next| {
+            if next.modulus <= T::ZERO {
+                return Err(NumError::InvalidModulus);
+            }
+            merge(acc, *next)
+        })
+}
+
+fn merge<T: Signed>(
+    first: Congruence<T>,
+    second: Congruence<T>,
+) -> Result<Congruence<T>, NumError> {
+    let residue = checked(|| second.residue.checked_rem_euclid(second.modulus))?;
+    let extended = checked(|| extended_gcd(first.modulus, second.modulus))?;
+    let gap = checked(|| residue.checked_sub(first.residue))?;
+    if checked(|| gap.checked_rem(extended.gcd))? != T::ZERO {
+        return Err(NumError::NoSolution);
+    }
+
+    // first.residue + first.modulus * k works for any k ≡ gap / gcd * x (mod second / gcd)
+    let step = checked(|| second.modulus.checked_div(extended.gcd))?;
+    let k = checked(|| gap.checked_div(extended.gcd)?.checked_rem_euclid(step))?;
+    let x = checked(|| extended.x.checked_rem_euclid(step))?;
+    let k = checked(|| k.checked_mul(x)?.checked_rem(step))?;
+
+    let modulus = checked(|| first.modulus.checked_mul(step))?;
+    let residue = checked(|| {
+        first
+            .modulus
+            .checked_mul(k)?
+            .checked_add(first.residue)?
+            .checked_rem_euclid(modulus)
+    })?;
+    Ok(Congruence { residue, modulus })
+}
+
+#[cfg(test)]
+mod test_num {
+    use crate::num::{
+        crt, extended_gcd, gcd, lcm, mod_inverse, mod_pow, Congruence, ExtendedGcd, NumError,
+    };
+
+    fn brute_gcd(a: i64, b: i64) -> i64 {
+        (1..=a.abs().max(b.abs()))
+            .rev()
+            .find(|d| a % d == 0 && b % d == 0)
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn test_gcd_lcm_against_brute_force() {
+        for a in -30..=30i64 {
+            for b in -30..=30i64 {
+                let expected = brute_gcd(a, b);
+                assert_eq!(Some(expected), gcd(a, b), "gcd({a}, {b})");
+
+                let expected_lcm = if a == 0 || b == 0 {
+                    0
+                } else {
+                    (1..).find(|m| m % a == 0 && m % b == 0).unwrap()
+                };
+                assert_eq!(Some(expected_lcm), lcm(a, b), "lcm({a}, {b})");
+
+                let ExtendedGcd { gcd, x, y } = extended_gcd(a, b).unwrap();
+                assert_eq!(expected, gcd);
+                assert_eq!(gcd, a * x + b * y, "extended_gcd({a}, {b})");
+            }
+        }
+        assert_eq!(Some(6u32), gcd(12u32, 18u32));
+    }
+
+    #[test]
+    fn test_overflow_is_reported() {
+        assert_eq!(None, gcd(i64::MIN, 0));
+        assert_eq!(None, lcm(u8::MAX, u8::MAX - 1));
+        assert_eq!(Some(u8::MAX), lcm(u8::MAX, 5));
+        assert_eq!(Err(NumError::Overflow), mod_pow(3u8, 5, 200));
+        assert_eq!(
+            Err(NumError::Overflow),
+            crt(&[
+                Congruence::new(1i64, 1_000_000_007),
+                Congruence::new(2, 998_244_353),
+                Congruence::new(3, 1_000_000_009),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_mod_inverse_and_pow() {
+        for modulus in 1..40i64 {
+            for value in -40..40i64 {
+                let expected =
+                    (0..modulus).find(|x| (value * x).rem_euclid(modulus) == 1 % modulus);
+                match mod_inverse(value, modulus) {
+                    Ok(inverse) => assert_eq!(expected, Some(inverse)),
+                    Err(err) => {
+                        assert_eq!(NumError::NotInvertible, err);
+                        assert_eq!(None, expected);
+                    }
+                }
+                for exp in 0..10u32 {
+                    let expected =
+                        (0..exp).fold(1 % modulus, |acc, _| (acc * value).rem_euclid(modulus));
+                    assert_eq!(Ok(expected), mod_pow(value, exp as u64, modulus));
+                }
+            }
+        }
+        assert_eq!(Err(NumError::InvalidModulus), mod_inverse(3, 0));
+    }
+
+    #[test]
+    fn test_crt_against_brute_force() {
+        for m1 in 1..15i64 {
+            for m2 in 1..15i64 {
+                for r1 in 0..m1 {
+                    for r2 in -2..m2 {
+                        let congruences = [Congruence::new(r1, m1), Congruence::new(r2, m2)];
+                        let modulus = lcm(m1, m2).unwrap();
+                        let expected = (0..modulus)
+                            .find(|x| (x - r1).rem_euclid(m1) == 0 && (x - r2).rem_euclid(m2) == 0);
+                        match expected {
+                            Some(residue) => assert_eq!(
+                                Ok(Congruence::new(residue, modulus)),
+                                crt(&congruences),
+                                "{congruences:?}"
+                            ),
+                            None => assert_eq!(Err(NumError::NoSolution), crt(&congruences)),
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(Ok(Congruence::new(0, 1)), crt::<i64>(&[]));
+        assert_eq!(
+            Ok(Congruence::new(23, 105)),
+            crt(&[
+                Congruence::new(2, 3),
+                Congruence::new(3, 5),
+                Congruence::new(2, 7)
+            ])
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// A value a day needs that isn't part of its input, like grid bounds that differ between the
+/// example and the real input. Declared with a default in `Puzzle::PARAMS`.
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: ParamValue,
+}
+
+impl Param {
+    pub const fn int(name: &'static str, default: i64) -> Self {
+        Param {
+            name,
+            default: ParamValue::Int(default),
+        }
+    }
+
+    pub const fn bool(name: &'static str, default: bool) -> Self {
+        Param {
+            name,
+            default: ParamValue::Bool(default),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ParamValue {
+    Int(i64),
+    Bool(bool),
+}
+
+impl ParamValue {
+    // Overrides have to be the same type as the default they replace
+    fn parse_like(&self, value: &str) -> Option<ParamValue> {
+        match self {
+            ParamValue::Int(_) => value.parse().ok().map(ParamValue::Int),
+            ParamValue::Bool(_) => value.parse().ok().map(ParamValue::Bool),
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            ParamValue::Int(_) => "an integer",
+            ParamValue::Bool(_) => "true or false",
+        }
+    }
+}
+
+impl Display for ParamValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamValue::Int(value) => write!(f, "{value}"),
+            ParamValue::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ParamError {
+    Unknown(String),
+    WrongType {
+        name: String,
+        value: String,
+        expected: &'static str,
+    },
+    // A line in a sidecar file or a `--param` that isn't `key=value`
+    Malformed(String),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ParamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamError::Unknown(name) => write!(f, "no param named {name}"),
+            ParamError::WrongType {
+                name,
+                value,
+                expected,
+            } => write!(f, "{name} has to be {expected}, got {value:?}"),
+            ParamError::Malformed(line) => write!(f, "expected key=value, got {line:?}"),
+            ParamError::Io(path, err) => write!(f, "couldn't read {:?}: {err}", path),
+        }
+    }
+}
+
+/// Splits a `key=value` pair, trimming whitespace around both.
+pub fn parse_assignment(assignment: &str) -> Result<(String, String), ParamError> {
+    let (key, value) = assignment
+        .split_once('=')
+        .ok_or_else(|| ParamError::Malformed(assignment.to_string()))?;
+    Ok((key.trim().to_string(), value.trim().to_string()))
+}
+
+/// Params for `inputs/2024/day-14-example.txt` live in `inputs/2024/day-14-example.params`.
+pub fn sidecar_path(input_path: &Path) -> PathBuf {
+    input_path.with_extension("params")
+}
+
+/// Reads the `key=value` lines of a sidecar file. Blank lines and `#` comments are skipped and a
+/// missing file has no overrides.
+pub fn read_sidecar(path: &Path) -> Result<Vec<(String, String)>, ParamError> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(ParamError::Io(path.to_path_buf(), err)),
+    };
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(parse_assignment)
+        .collect()
+}
+
+/// The value of every declared param for one run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Params(BTreeMap<String, ParamValue>);
+
+impl Params {
+    pub fn defaults(declared: &[Param]) -> Self {
+        Params(
+            declared
+                .iter()
+                .map(|param| (param.name.to_string(), param.default))
+                .collect(),
+        )
+    }
+
+    /// Starts from the defaults and applies the sidecar file next to the input, then `overrides`,
+    /// so the command line wins.
+    pub fn resolve(
+        declared: &[Param],
+        input_path: &Path,
+        overrides: &[(String, String)],
+    ) -> Result<Self, ParamError> {
+        let mut params = Params::defaults(declared);
+        params.apply(&read_sidecar(&sidecar_path(input_path))?)?;
+        params.apply(overrides)?;
+        Ok(params)
+    }
+
+    /// Overrides declared params in order, so later assignments win.
+    pub fn apply(&mut self, overrides: &[(String, String)]) -> Result<(), ParamError> {
+        for (name, value) in overrides {
+            self.set(name, value)?;
+        }
+        Ok(())
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        let current = self
+            .0
+            .get_mut(name)
+            .ok_or_else(|| ParamError::Unknown(name.to_string()))?;
+        *current = current
+            .parse_like(value)
+            .ok_or_else(|| ParamError::WrongType {
+                name: name.to_string(),
+                value: value.to_string(),
+                expected: current.kind(),
+            })?;
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<ParamValue> {
+        self.0.get(name).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &ParamValue)> {
+        self.0.iter()
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Params> = RefCell::new(Params::default());
+}
+
+/// Makes `params` readable through `int` and `bool` while `f` runs on this thread.
+pub fn with<T>(params: Params, f: impl FnOnce() -> T) -> T {
+    // Put the previous params back even if `f` panics
+    struct Restore(Option<Params>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            if let Some(previous) = self.0.take() {
+                CURRENT.with(|current| *current.borrow_mut() = previous);
+            }
+        }
+    }
+
+    let _restore = Restore(Some(CURRENT.with(|current| current.replace(params))));
+    f()
+}
+
+fn current(name: &str) -> ParamValue {
+    CURRENT
+        .with(|current| current.borrow().get(name))
+        .unwrap_or_else(|| panic!("no param named {name}, is it in the day's PARAMS?"))
+}
+
+/// Reads an integer param of the part currently running, converted to whatever type is needed.
+pub fn int<T: TryFrom<i64>>(name: &str) -> T {
+    match current(name) {
+        ParamValue::Int(value) => T::try_from(value)
+            .unwrap_or_else(|_| panic!("param {name} = {value} doesn't fit the requested type")),
+        other => panic!("param {name} is {other}, not an integer"),
+    }
+}
+
+pub fn bool(name: &str) -> bool {
+    match current(name) {
+        ParamValue::Bool(value) => value,
+        other => panic!("param {name} is {other}, not true or false"),
+    }
+}
+
+#[cfg(test)]
+mod test_params {
+    use std::fs;
+
+    use crate::params::{self, parse_assignment, Param, ParamError, ParamValue, Params};
+
+    const DECLARED: &[Param] = &[Param::int("width", 101), Param::bool("draw", false)];
+
+    #[test]
+    fn test_resolve_order() {
+        let dir = std::env::temp_dir().join(format!("aoc-params-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let input_path = dir.join("day-14-example.txt");
+        fs::write(
+            dir.join("day-14-example.params"),
+            "# the example is smaller\nwidth = 11\n\ndraw=true\n",
+        )
+        .unwrap();
+
+        let params = Params::resolve(DECLARED, &input_path, &[]).unwrap();
+        assert_eq!(Some(ParamValue::Int(11)), params.get("width"));
+        assert_eq!(Some(ParamValue::Bool(true)), params.get("draw"));
+
+        let overrides = [parse_assignment("width=7").unwrap()];
+        let params = Params::resolve(DECLARED, &input_path, &overrides).unwrap();
+        assert_eq!(Some(ParamValue::Int(7)), params.get("width"));
+
+        let params = Params::resolve(DECLARED, &dir.join("day-14.txt"), &[]).unwrap();
+        assert_eq!(Params::defaults(DECLARED), params);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_rejects_bad_overrides() {
+        let input_path = std::env::temp_dir()
+            .join("aoc-params-none")
+            .join("day-1.txt");
+        let unknown = [("height".to_string(), "7".to_string())];
+        assert!(matches!(
+            Params::resolve(DECLARED, &input_path, &unknown),
+            Err(ParamError::Unknown(_))
+        ));
+        let wrong_type = [("width".to_string(), "wide".to_string())];
+        assert!(matches!(
+            Params::resolve(DECLARED, &input_path, &wrong_type),
+            Err(ParamError::WrongType { .. })
+        ));
+        assert!(matches!(
+            parse_assignment("width"),
+            Err(ParamError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn test_with_scopes_params() {
+        let width: usize = params::with(Params::defaults(DECLARED), || params::int("width"));
+        assert_eq!(101, width);
+        assert!(!params::with(Params::defaults(DECLARED), || params::bool(
+            "draw"
+        )));
+
+        let outside = std::panic::catch_unwind(|| params::int::<i64>("width"));
+        assert!(outside.is_err());
+    }
+}
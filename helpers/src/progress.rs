@@ -0,0 +1,185 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Redrawing more often than this only slows the part down
+const REDRAW_EVERY: Duration = Duration::from_millis(100);
+
+// Checked before anything else so progress costs next to nothing when it isn't shown
+static ENABLED: AtomicBool = AtomicBool::new(false);
+// Set while parts run side by side, when their lines would only draw over each other
+static HIDDEN: AtomicBool = AtomicBool::new(false);
+static LINE: Mutex<StatusLine> = Mutex::new(StatusLine {
+    prefix: String::new(),
+    last_drawn: None,
+});
+
+struct StatusLine {
+    // Which part is running, set by `with`
+    prefix: String,
+    last_drawn: Option<Instant>,
+}
+
+impl StatusLine {
+    fn draw(&mut self, label: &str, done: usize, total: Option<usize>) {
+        let now = Instant::now();
+        if self
+            .last_drawn
+            .is_some_and(|last| now.duration_since(last) < REDRAW_EVERY)
+        {
+            return;
+        }
+        self.last_drawn = Some(now);
+        let mut stderr = io::stderr().lock();
+        let _ = write!(
+            stderr,
+            "\r\x1b[2K{}",
+            format_line(&self.prefix, label, done, total)
+        );
+        let _ = stderr.flush();
+    }
+
+    fn clear(&mut self) {
+        if self.last_drawn.take().is_some() {
+            let mut stderr = io::stderr().lock();
+            let _ = write!(stderr, "\r\x1b[2K");
+            let _ = stderr.flush();
+        }
+    }
+}
+
+fn format_line(prefix: &str, label: &str, done: usize, total: Option<usize>) -> String {
+    let Some(total) = total else {
+        return format!("{prefix}: {label} {done}");
+    };
+    let percent = if total == 0 {
+        100.0
+    } else {
+        done as f64 * 100.0 / total as f64
+    };
+    format!("{prefix}: {label} {done}/{total} ({percent:.0}%)")
+}
+
+/// Runs `f` with progress shown as a status line on stderr, starting with `prefix`. Nothing is
+/// shown when stderr isn't a terminal, so piped output never gets the escape codes.
+pub fn with<T>(prefix: String, f: impl FnOnce() -> T) -> T {
+    if HIDDEN.load(Ordering::Relaxed) || !io::stderr().is_terminal() {
+        return f();
+    }
+    // Turns drawing back off even if `f` panics
+    struct Shown;
+    impl Drop for Shown {
+        fn drop(&mut self) {
+            ENABLED.store(false, Ordering::Relaxed);
+            let mut line = LINE.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            line.clear();
+        }
+    }
+
+    LINE.lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .prefix = prefix;
+    ENABLED.store(true, Ordering::Relaxed);
+    let _shown = Shown;
+    f()
+}
+
+/// Runs `f` with no progress shown, from any thread, for running several parts at once.
+pub fn hidden<T>(f: impl FnOnce() -> T) -> T {
+    struct Unhide;
+    impl Drop for Unhide {
+        fn drop(&mut self) {
+            HIDDEN.store(false, Ordering::Relaxed);
+        }
+    }
+
+    HIDDEN.store(true, Ordering::Relaxed);
+    let _unhide = Unhide;
+    f()
+}
+
+/// How far a part is through its work, drawn by the runner when it's showing progress. The
+/// line's cleared again once this is dropped.
+pub struct Progress {
+    label: &'static str,
+    done: usize,
+    // Unknown for work that's only counted as it comes, like lines of streamed input
+    total: Option<usize>,
+}
+
+impl Progress {
+    pub fn new(label: &'static str, total: usize) -> Self {
+        Progress {
+            label,
+            done: 0,
+            total: Some(total),
+        }
+    }
+
+    /// Progress with no end known ahead of time, shown as a plain count.
+    pub fn counting(label: &'static str) -> Self {
+        Progress {
+            label,
+            done: 0,
+            total: None,
+        }
+    }
+
+    pub fn done(&self) -> usize {
+        self.done
+    }
+
+    /// Marks one more piece of work done.
+    pub fn inc(&mut self) {
+        self.set(self.done + 1);
+    }
+
+    pub fn set(&mut self, done: usize) {
+        self.done = done;
+        if ENABLED.load(Ordering::Relaxed) {
+            if let Ok(mut line) = LINE.lock() {
+                line.draw(self.label, self.done, self.total);
+            }
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if ENABLED.load(Ordering::Relaxed) {
+            if let Ok(mut line) = LINE.lock() {
+                line.clear();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_progress {
+    use crate::progress::{format_line, with, Progress};
+
+    #[test]
+    fn test_format_line() {
+        assert_eq!(
+            "2024 day 22 part 2: buyers 3/8 (38%)",
+            format_line("2024 day 22 part 2", "buyers", 3, Some(8))
+        );
+        assert_eq!(
+            "x: nothing 0/0 (100%)",
+            format_line("x", "nothing", 0, Some(0))
+        );
+        assert_eq!("x: lines 12", format_line("x", "lines", 12, None));
+    }
+
+    #[test]
+    fn test_counts_without_a_terminal() {
+        let done = with("test".to_string(), || {
+            let mut progress = Progress::new("steps", 10);
+            (0..4).for_each(|_| progress.inc());
+            progress.set(progress.done() + 2);
+            progress.done()
+        });
+        assert_eq!(6, done);
+    }
+}
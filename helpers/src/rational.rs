@@ -0,0 +1,160 @@
+use std::fmt::Display;
+
+use crate::num;
+
+/// An exact fraction kept in lowest terms with a positive denominator, so equal values always
+/// compare equal. Arithmetic is checked and returns `None` on overflow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: i128,
+    denom: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numer: 0, denom: 1 };
+    pub const ONE: Rational = Rational { numer: 1, denom: 1 };
+
+    /// `None` if `denom` is 0 or the fraction can't be normalized without overflowing.
+    pub fn new(numer: i128, denom: i128) -> Option<Rational> {
+        if denom == 0 {
+            return None;
+        }
+        let divisor = num::gcd(numer, denom)?;
+        let (numer, denom) = (numer / divisor, denom / divisor);
+        if denom < 0 {
+            Some(Rational {
+                numer: numer.checked_neg()?,
+                denom: denom.checked_neg()?,
+            })
+        } else {
+            Some(Rational { numer, denom })
+        }
+    }
+
+    pub fn numer(&self) -> i128 {
+        self.numer
+    }
+
+    pub fn denom(&self) -> i128 {
+        self.denom
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numer == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == 1
+    }
+
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.numer)
+    }
+
+    pub fn signum(&self) -> i128 {
+        self.numer.signum()
+    }
+
+    pub fn checked_neg(self) -> Option<Rational> {
+        Some(Rational {
+            numer: self.numer.checked_neg()?,
+            ..self
+        })
+    }
+
+    /// `None` for zero as well as on overflow.
+    pub fn checked_recip(self) -> Option<Rational> {
+        Rational::new(self.denom, self.numer)
+    }
+
+    pub fn checked_add(self, other: Rational) -> Option<Rational> {
+        // Scaling both to the lcm of the denominators keeps the numbers as small as they can be
+        let divisor = num::gcd(self.denom, other.denom)?;
+        let numer = self
+            .numer
+            .checked_mul(other.denom / divisor)?
+            .checked_add(other.numer.checked_mul(self.denom / divisor)?)?;
+        Rational::new(numer, (self.denom / divisor).checked_mul(other.denom)?)
+    }
+
+    pub fn checked_sub(self, other: Rational) -> Option<Rational> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, other: Rational) -> Option<Rational> {
+        // Cancel across first so the products only overflow if the answer would
+        let first = num::gcd(self.numer, other.denom)?;
+        let second = num::gcd(other.numer, self.denom)?;
+        Rational::new(
+            (self.numer / first).checked_mul(other.numer / second)?,
+            (self.denom / second).checked_mul(other.denom / first)?,
+        )
+    }
+
+    pub fn checked_div(self, other: Rational) -> Option<Rational> {
+        self.checked_mul(other.checked_recip()?)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Rational {
+            numer: value,
+            denom: 1,
+        }
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational::from(value as i128)
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_rational {
+    use crate::rational::Rational;
+
+    fn r(numer: i128, denom: i128) -> Rational {
+        Rational::new(numer, denom).unwrap()
+    }
+
+    #[test]
+    fn test_normalized() {
+        assert_eq!(r(1, 2), r(-3, -6));
+        assert_eq!((-2, 3), (r(4, -6).numer(), r(4, -6).denom()));
+        assert_eq!(Rational::ZERO, r(0, -5));
+        assert_eq!(None, Rational::new(1, 0));
+        assert_eq!(None, Rational::new(1, i128::MIN));
+        assert_eq!("-2/3", r(4, -6).to_string());
+        assert_eq!("7", r(14, 2).to_string());
+        assert_eq!(Some(7), r(14, 2).to_integer());
+        assert_eq!(None, r(1, 2).to_integer());
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        assert_eq!(Some(r(5, 6)), r(1, 2).checked_add(r(1, 3)));
+        assert_eq!(Some(r(1, 6)), r(1, 2).checked_sub(r(1, 3)));
+        assert_eq!(Some(r(1, 6)), r(1, 2).checked_mul(r(1, 3)));
+        assert_eq!(Some(r(3, 2)), r(1, 2).checked_div(r(1, 3)));
+        assert_eq!(None, r(1, 2).checked_div(Rational::ZERO));
+        assert_eq!(Some(Rational::ZERO), Rational::ZERO.checked_mul(r(3, 7)));
+
+        // Cancelling across keeps these in range even though the raw products aren't
+        let big = r(i128::MAX, 3);
+        assert_eq!(Some(Rational::from(i128::MAX)), big.checked_mul(r(3, 1)));
+        assert_eq!(None, big.checked_mul(r(4, 1)));
+        assert_eq!(None, Rational::from(i128::MAX).checked_add(Rational::ONE));
+    }
+}
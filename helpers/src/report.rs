@@ -0,0 +1,183 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::runner::{PartReport, Status};
+
+/// Everything from one invocation of the runner, in the order the parts ran.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunReport {
+    // Seconds since the unix epoch
+    pub timestamp: u64,
+    pub parts: Vec<PartReport>,
+}
+
+impl RunReport {
+    pub fn new(parts: Vec<PartReport>) -> Self {
+        RunReport {
+            timestamp: unix_timestamp(),
+            parts,
+        }
+    }
+
+    pub fn count(&self, status: Status) -> usize {
+        self.parts
+            .iter()
+            .filter(|part| part.status == status)
+            .count()
+    }
+
+    pub fn cached(&self) -> usize {
+        self.parts.iter().filter(|part| part.cached).count()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report is always serializable")
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut table = String::new();
+        table
+            .push_str("| Year | Day | Part | Answer | Status | Read (ms) | Solve (ms) | Input |\n");
+        table.push_str(
+            "|-----:|----:|-----:|--------|--------|-----------:|-----------:|-------|\n",
+        );
+        for part in self.parts.iter() {
+            table.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {:.3} | {:.3} | {} |\n",
+                part.year,
+                part.day,
+                part.part,
+                part.answer
+                    .as_deref()
+                    .map(|answer| format!("`{answer}`"))
+                    .unwrap_or_else(|| "-".to_string()),
+                if part.cached {
+                    format!("{} (cached)", part.status)
+                } else {
+                    part.status.to_string()
+                },
+                part.read_ms,
+                part.solve_ms,
+                part.input_hash
+                    .as_deref()
+                    .map(|hash| format!("`{}`", &hash[..8]))
+                    .unwrap_or_else(|| "-".to_string()),
+            ));
+        }
+        table
+    }
+
+    pub fn write(&self, json_path: &Path, markdown_path: &Path) -> io::Result<()> {
+        for (path, contents) in [
+            (json_path, self.to_json()),
+            (markdown_path, self.to_markdown()),
+        ] {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, contents)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("clock is after the epoch")
+        .as_secs()
+}
+
+/// Formats seconds since the epoch as a UTC `YYYY-MM-DD HH:MM:SS` string.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let seconds = timestamp % 86_400;
+
+    // Civil-from-days, see http://howardhinnant.github.io/date_algorithms.html
+    let shifted = days + 719_468;
+    let era = shifted.div_euclid(146_097);
+    let day_of_era = shifted.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}",
+        seconds / 3_600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod test_report {
+    use crate::params::Params;
+    use crate::report::{format_timestamp, RunReport};
+    use crate::runner::{PartReport, Status};
+
+    fn part(day: u32, status: Status, answer: Option<&str>) -> PartReport {
+        PartReport {
+            year: 2024,
+            day,
+            part: 1,
+            variant: "default".to_string(),
+            input: format!("day-{day}.txt"),
+            input_hash: answer.map(|_| "0123456789abcdef".to_string()),
+            answer: answer.map(str::to_string),
+            expected: None,
+            status,
+            params: Params::default(),
+            error: None,
+            read_ms: 0.5,
+            solve_ms: 12.25,
+            read_allocs: None,
+            solve_allocs: None,
+            streamed: false,
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn test_report_formats() {
+        let report = RunReport::new(vec![
+            part(1, Status::Correct, Some("11")),
+            part(2, Status::MissingInput, None),
+        ]);
+        assert_eq!(1, report.count(Status::Correct));
+
+        let markdown = report.to_markdown();
+        let rows = markdown.lines().collect::<Vec<_>>();
+        assert_eq!(4, rows.len());
+        assert_eq!(
+            "| 2024 | 1 | 1 | `11` | correct | 0.500 | 12.250 | `01234567` |",
+            rows[2]
+        );
+        assert_eq!(
+            "| 2024 | 2 | 1 | - | missing input | 0.500 | 12.250 | - |",
+            rows[3]
+        );
+
+        let parsed: RunReport = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(2, parsed.parts.len());
+        assert_eq!(Status::MissingInput, parsed.parts[1].status);
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!("1970-01-01 00:00:00", format_timestamp(0));
+        assert_eq!("2024-12-01 05:00:00", format_timestamp(1_733_029_200));
+        assert_eq!("2000-02-29 23:59:59", format_timestamp(951_868_799));
+    }
+}
@@ -0,0 +1,818 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use serde::{Deserialize, Serialize};
+
+use crate::alloc::AllocStats;
+use crate::cache::{Cache, CachedAnswer};
+use crate::meta::{SolverInfo, Tag};
+use crate::params::{self, Param, Params};
+use crate::progress;
+use crate::{measure_stage, Answer, Puzzle, StageReport};
+
+pub type PuzzleFn = fn(String) -> Answer;
+pub type StreamFn = fn(&mut dyn BufRead) -> Answer;
+
+/// A type-erased `Puzzle` so days can be stored side by side and run by number.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub year: u32,
+    pub day: u32,
+    pub variant: &'static str,
+    pub title: &'static str,
+    pub tags: &'static [Tag],
+    pub helpers: &'static [&'static str],
+    pub notes: &'static str,
+    pub params: &'static [Param],
+    pub puzzle_1: PuzzleFn,
+    pub puzzle_2: PuzzleFn,
+    pub stream_1: StreamFn,
+    pub stream_2: StreamFn,
+}
+
+impl Solver {
+    pub fn of<P: Puzzle + ?Sized>() -> Self {
+        Solver {
+            year: P::YEAR,
+            day: P::DAY,
+            variant: P::VARIANT,
+            title: P::TITLE,
+            tags: P::TAGS,
+            helpers: P::HELPERS,
+            notes: P::NOTES,
+            params: P::PARAMS,
+            puzzle_1: P::puzzle_1,
+            puzzle_2: P::puzzle_2,
+            stream_1: P::stream_1,
+            stream_2: P::stream_2,
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<PuzzleFn> {
+        match part {
+            1 => Some(self.puzzle_1),
+            2 => Some(self.puzzle_2),
+            _ => None,
+        }
+    }
+
+    pub fn stream(&self, part: u8) -> Option<StreamFn> {
+        match part {
+            1 => Some(self.stream_1),
+            2 => Some(self.stream_2),
+            _ => None,
+        }
+    }
+
+    pub fn input_name(&self) -> String {
+        format!("day-{}.txt", self.day)
+    }
+
+    /// Each year keeps its inputs in `inputs/<year>/`
+    pub fn input_dir(&self, root: &Path) -> PathBuf {
+        root.join("inputs").join(self.year.to_string())
+    }
+
+    pub fn input_path(&self, root: &Path) -> PathBuf {
+        self.input_dir(root).join(self.input_name())
+    }
+}
+
+/// Every solver in the workspace keyed by `(year, day)`.
+#[derive(Default)]
+pub struct Registry {
+    solvers: BTreeMap<(u32, u32), Solver>,
+}
+
+impl Registry {
+    pub fn add<P: Puzzle>(&mut self) {
+        let solver = Solver::of::<P>();
+        if self
+            .solvers
+            .insert((solver.year, solver.day), solver)
+            .is_some()
+        {
+            panic!("{} day {} registered twice", solver.year, solver.day);
+        }
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Option<&Solver> {
+        self.solvers.get(&(year, day))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Solver> {
+        self.solvers.values()
+    }
+
+    pub fn year(&self, year: u32) -> impl Iterator<Item = &Solver> {
+        self.solvers
+            .range((year, 0)..=(year, u32::MAX))
+            .map(|(_, solver)| solver)
+    }
+
+    /// Every solver's metadata, in year and day order.
+    pub fn infos(&self) -> Vec<SolverInfo> {
+        self.iter().map(SolverInfo::of).collect()
+    }
+
+    pub fn years(&self) -> Vec<u32> {
+        let mut years = self
+            .solvers
+            .keys()
+            .map(|(year, _)| *year)
+            .collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Correct,
+    Incorrect,
+    // No expected answer recorded for this input yet
+    Unverified,
+    Panicked,
+    MissingInput,
+    // The input isn't utf-8, so there's no handing it to the part as a `String`
+    InvalidInput,
+    // The sidecar file or `--param` didn't match the day's declared params
+    InvalidParams,
+    // Only from isolated runs, which can kill a part or see it die outright
+    TimedOut,
+    OutOfMemory,
+    // The child died some other way, like a stack overflow
+    Crashed,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Status::Correct => "correct",
+            Status::Incorrect => "incorrect",
+            Status::Unverified => "unverified",
+            Status::Panicked => "panicked",
+            Status::MissingInput => "missing input",
+            Status::InvalidInput => "invalid input",
+            Status::InvalidParams => "invalid params",
+            Status::TimedOut => "timed out",
+            Status::OutOfMemory => "out of memory",
+            Status::Crashed => "crashed",
+        };
+        write!(f, "{text}")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartReport {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub variant: String,
+    pub input: String,
+    pub input_hash: Option<String>,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub status: Status,
+    #[serde(default)]
+    pub params: Params,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    // Only reading the input file, parsing it is up to the part so it counts as solving.
+    // Called parse before that was made clear
+    #[serde(alias = "parse_ms")]
+    pub read_ms: f64,
+    pub solve_ms: f64,
+    #[serde(alias = "parse_allocs")]
+    pub read_allocs: Option<AllocStats>,
+    pub solve_allocs: Option<AllocStats>,
+    // Read a chunk at a time by the part rather than up front, so read only covers opening it
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub streamed: bool,
+    // The answer came out of the cache, so solve only covers looking it up
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub cached: bool,
+}
+
+impl Display for PartReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} day {} part {}: {} [{}] (read {:.3} ms, solve {:.3} ms)",
+            self.year,
+            self.day,
+            self.part,
+            self.answer.as_deref().unwrap_or("-"),
+            self.status,
+            self.read_ms,
+            self.solve_ms
+        )?;
+        if self.streamed {
+            write!(f, " streamed")?;
+        }
+        if self.cached {
+            write!(f, " cached")?;
+        }
+        if let (Some(expected), Status::Incorrect) = (&self.expected, self.status) {
+            write!(f, "\n  expected: {expected}")?;
+        }
+        if let Some(error) = &self.error {
+            write!(f, "\n  error: {error}")?;
+        }
+        if let Some(allocs) = &self.read_allocs {
+            write!(f, "\n  read {allocs}")?;
+        }
+        if let Some(allocs) = &self.solve_allocs {
+            write!(f, "\n  solve {allocs}")?;
+        }
+        Ok(())
+    }
+}
+
+impl PartReport {
+    // For runs that never got as far as solving
+    pub(crate) fn not_run(solver: &Solver, part: u8, input: String, status: Status) -> Self {
+        PartReport {
+            year: solver.year,
+            day: solver.day,
+            part,
+            variant: solver.variant.to_string(),
+            input,
+            input_hash: None,
+            answer: None,
+            expected: None,
+            status,
+            params: Params::default(),
+            error: None,
+            read_ms: 0.0,
+            solve_ms: 0.0,
+            read_allocs: None,
+            solve_allocs: None,
+            streamed: false,
+            cached: false,
+        }
+    }
+}
+
+/// FNV-1a, so the same input always hashes the same across builds and toolchains.
+pub fn hash_input(bytes: &[u8]) -> String {
+    let mut hasher = InputHasher::default();
+    hasher.update(bytes);
+    hasher.finish()
+}
+
+// `hash_input` a piece at a time, for input that's never all in memory
+struct InputHasher(u64);
+
+impl Default for InputHasher {
+    fn default() -> Self {
+        InputHasher(0xcbf29ce484222325)
+    }
+}
+
+impl InputHasher {
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
+
+// Hashes everything the part reads on its way past
+struct HashingReader<R> {
+    inner: R,
+    hasher: InputHasher,
+}
+
+impl<R: BufRead> HashingReader<R> {
+    // Parts can stop reading early, the rest still counts towards the hash
+    fn finish(mut self) -> io::Result<String> {
+        io::copy(&mut self, &mut io::sink())?;
+        Ok(self.hasher.finish())
+    }
+}
+
+impl<R: BufRead> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+impl<R: BufRead> BufRead for HashingReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        // Whatever's being consumed is still in the buffer, so this never actually reads
+        if let Ok(buffered) = self.inner.fill_buf() {
+            self.hasher.update(&buffered[..amt.min(buffered.len())]);
+        }
+        self.inner.consume(amt);
+    }
+}
+
+// What reports call the input, its file name
+pub(crate) fn input_label(input_path: &Path) -> String {
+    input_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Mirrors an input's path under the nearest `inputs` directory into `answers`, so
+/// `inputs/2024/day-1.txt` is checked against `answers/2024/day-1.txt`.
+pub fn answers_path(input_path: &Path) -> Option<PathBuf> {
+    let inputs_dir = input_path
+        .ancestors()
+        .find(|ancestor| ancestor.file_name().is_some_and(|name| name == "inputs"))?;
+    let relative = input_path.strip_prefix(inputs_dir).ok()?;
+    Some(inputs_dir.parent()?.join("answers").join(relative))
+}
+
+/// Answers kept right next to their input, `alice.txt` with `alice.answers`, for inputs that
+/// don't live under `inputs`.
+pub fn answers_sidecar_path(input_path: &Path) -> PathBuf {
+    input_path.with_extension("answers")
+}
+
+/// Expected answers files hold part 1 on the first line and part 2 on the second. Blank lines
+/// mean the answer isn't known yet. A sidecar file wins over the one under `answers`.
+pub fn expected_answers(input_path: &Path) -> [Option<String>; 2] {
+    let contents = fs::read_to_string(answers_sidecar_path(input_path))
+        .ok()
+        .or_else(|| answers_path(input_path).and_then(|path| fs::read_to_string(path).ok()))
+        .unwrap_or_default();
+    let mut lines = contents
+        .lines()
+        .map(|line| Some(line.trim().to_string()).filter(|line| !line.is_empty()));
+    [lines.next().flatten(), lines.next().flatten()]
+}
+
+/// Reads the input, runs one part against it and checks the answer. Returns `None` for a
+/// part number that isn't 1 or 2. Panics inside the part are caught and reported.
+///
+/// The part sees the day's params with the input's sidecar file and then `overrides` applied.
+pub fn run_part(
+    solver: &Solver,
+    part: u8,
+    input_path: &Path,
+    overrides: &[(String, String)],
+) -> Option<PartReport> {
+    run_part_with(solver, part, input_path, overrides, None)
+}
+
+/// Like `run_part`, but answers from `cache` when it has one for this input and caches
+/// whatever the part comes up with when it doesn't.
+pub fn run_part_with(
+    solver: &Solver,
+    part: u8,
+    input_path: &Path,
+    overrides: &[(String, String)],
+    cache: Option<&Cache>,
+) -> Option<PartReport> {
+    solver.part(part)?;
+    let run = match Run::prepare(solver, part, input_path, overrides) {
+        Ok(run) => run,
+        Err(report) => return Some(*report),
+    };
+
+    let (bytes, read_report) = measure_stage("read", || fs::read(input_path));
+    let bytes = match bytes {
+        Ok(bytes) => bytes,
+        // Only happens under an isolated run's memory limit
+        Err(err) if err.kind() == io::ErrorKind::OutOfMemory => {
+            let mut report = PartReport::not_run(solver, part, run.name, Status::OutOfMemory);
+            report.error = Some(format!("couldn't read the input: {err}"));
+            return Some(report);
+        }
+        Err(_) => {
+            return Some(PartReport::not_run(
+                solver,
+                part,
+                run.name,
+                Status::MissingInput,
+            ))
+        }
+    };
+
+    let Some(cache) = cache else {
+        return run_input(solver, part, run.into_input(bytes), read_report);
+    };
+    let input_hash = hash_input(&bytes);
+    let key = cache.key(solver, part, &input_hash, &run.params);
+    if let Some(key) = &key {
+        let (hit, solve_report) = measure_stage("solve", || cache.get(key));
+        if let Some(hit) = hit {
+            let answer = Ok(hit.answer.into());
+            let mut report = run.report(Some(input_hash), answer, read_report, solve_report);
+            report.cached = true;
+            return Some(report);
+        }
+    }
+
+    let report = run_input(solver, part, run.into_input(bytes), read_report)?;
+    if let (Some(key), Some(answer)) = (key, &report.answer) {
+        let cached = CachedAnswer {
+            answer: answer.clone(),
+            solve_ms: report.solve_ms,
+        };
+        // The cache only saves time, not being able to write it is no reason to fail the run
+        let _ = cache.put(&key, &cached);
+    }
+    Some(report)
+}
+
+/// Like `run_part` but hands the part the open file to read as it goes, through the day's
+/// `Puzzle::stream_1`/`stream_2`, rather than reading it all first.
+pub fn stream_part(
+    solver: &Solver,
+    part: u8,
+    input_path: &Path,
+    overrides: &[(String, String)],
+) -> Option<PartReport> {
+    let stream = solver.stream(part)?;
+    let run = match Run::prepare(solver, part, input_path, overrides) {
+        Ok(run) => run,
+        Err(report) => return Some(*report),
+    };
+
+    let (file, read_report) = measure_stage("read", || File::open(input_path));
+    let Ok(file) = file else {
+        return Some(PartReport::not_run(
+            solver,
+            part,
+            run.name,
+            Status::MissingInput,
+        ));
+    };
+    let mut reader = HashingReader {
+        inner: BufReader::new(file),
+        hasher: InputHasher::default(),
+    };
+    let (answer, solve_report) = run.solve(|| stream(&mut reader));
+    let input_hash = reader.finish().ok();
+
+    let mut report = run.report(input_hash, answer, read_report, solve_report);
+    report.streamed = true;
+    Some(report)
+}
+
+/// Input that's already been read, plus what's needed to run a part against it and check it.
+pub struct Input {
+    pub name: String,
+    pub bytes: Vec<u8>,
+    pub params: Params,
+    pub expected: Option<String>,
+}
+
+/// Like `run_part` for input that's already in memory, `read_report` being however long it
+/// took to get it there.
+pub fn run_input(
+    solver: &Solver,
+    part: u8,
+    input: Input,
+    read_report: StageReport,
+) -> Option<PartReport> {
+    let puzzle = solver.part(part)?;
+    let input_hash = hash_input(&input.bytes);
+    let contents = match String::from_utf8(input.bytes) {
+        Ok(contents) => contents,
+        Err(err) => {
+            let mut report = PartReport::not_run(solver, part, input.name, Status::InvalidInput);
+            report.input_hash = Some(input_hash);
+            report.error = Some(format!("input has to be utf-8: {}", err.utf8_error()));
+            return Some(report);
+        }
+    };
+
+    let run = Run {
+        solver,
+        part,
+        name: input.name,
+        params: input.params,
+        expected: input.expected,
+    };
+    let (answer, solve_report) = run.solve(|| puzzle(contents));
+    Some(run.report(Some(input_hash), answer, read_report, solve_report))
+}
+
+// A part about to be run against an input, with everything needed to report on it after
+struct Run<'a> {
+    solver: &'a Solver,
+    part: u8,
+    name: String,
+    params: Params,
+    expected: Option<String>,
+}
+
+impl<'a> Run<'a> {
+    // Fails with the report to give instead when the params don't check out
+    fn prepare(
+        solver: &'a Solver,
+        part: u8,
+        input_path: &Path,
+        overrides: &[(String, String)],
+    ) -> Result<Self, Box<PartReport>> {
+        let name = input_label(input_path);
+        match Params::resolve(solver.params, input_path, overrides) {
+            Ok(params) => Ok(Run {
+                solver,
+                part,
+                name,
+                params,
+                expected: expected_answers(input_path)[part as usize - 1].clone(),
+            }),
+            Err(err) => {
+                let mut report = PartReport::not_run(solver, part, name, Status::InvalidParams);
+                report.error = Some(err.to_string());
+                Err(Box::new(report))
+            }
+        }
+    }
+
+    fn into_input(self, bytes: Vec<u8>) -> Input {
+        Input {
+            name: self.name,
+            bytes,
+            params: self.params,
+            expected: self.expected,
+        }
+    }
+
+    // Panics inside the part are caught and come back as the `Err`
+    fn solve(&self, part: impl FnOnce() -> Answer) -> (thread::Result<Answer>, StageReport) {
+        let run_params = self.params.clone();
+        let label = format!(
+            "{} day {} part {}",
+            self.solver.year, self.solver.day, self.part
+        );
+        measure_stage("solve", || {
+            params::with(run_params, || {
+                progress::with(label, || panic::catch_unwind(AssertUnwindSafe(part)))
+            })
+        })
+    }
+
+    fn report(
+        self,
+        input_hash: Option<String>,
+        answer: thread::Result<Answer>,
+        read_report: StageReport,
+        solve_report: StageReport,
+    ) -> PartReport {
+        let (answer, status) = match answer {
+            Ok(answer) => {
+                let answer = answer.to_string();
+                let status = match &self.expected {
+                    Some(expected) if *expected == answer => Status::Correct,
+                    Some(_) => Status::Incorrect,
+                    None => Status::Unverified,
+                };
+                (Some(answer), status)
+            }
+            Err(_) => (None, Status::Panicked),
+        };
+
+        PartReport {
+            year: self.solver.year,
+            day: self.solver.day,
+            part: self.part,
+            variant: self.solver.variant.to_string(),
+            input: self.name,
+            input_hash,
+            answer,
+            expected: self.expected,
+            status,
+            params: self.params,
+            error: None,
+            read_ms: read_report.elapsed.as_secs_f64() * 1000.0,
+            solve_ms: solve_report.elapsed.as_secs_f64() * 1000.0,
+            read_allocs: read_report.allocs,
+            solve_allocs: solve_report.allocs,
+            streamed: false,
+            cached: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_runner {
+    use std::fs;
+
+    use crate::cache::{Cache, Fingerprints};
+    use crate::params::{self, Param, ParamValue};
+    use crate::runner::{
+        answers_path, answers_sidecar_path, expected_answers, hash_input, run_part, run_part_with,
+        stream_part, Registry, Solver, Status,
+    };
+    use crate::{Answer, Puzzle};
+
+    struct Doubler;
+
+    impl Puzzle for Doubler {
+        const YEAR: u32 = 2015;
+        const DAY: u32 = 99;
+
+        fn puzzle_1(contents: String) -> Answer {
+            (contents.trim().parse::<i64>().unwrap() * 2).into()
+        }
+
+        fn puzzle_2(_contents: String) -> Answer {
+            panic!("not solved yet")
+        }
+    }
+
+    #[test]
+    fn test_hash_input_is_stable() {
+        assert_eq!("cbf29ce484222325", hash_input(b""));
+        assert_eq!("af63dc4c8601ec8c", hash_input(b"a"));
+    }
+
+    #[test]
+    fn test_run_part_checks_expected() {
+        let root = std::env::temp_dir().join(format!("aoc-runner-{}", std::process::id()));
+        let solver = Solver::of::<Doubler>();
+        let input_path = solver.input_path(&root);
+        let answers_dir = root.join("answers").join("2015");
+        fs::create_dir_all(solver.input_dir(&root)).unwrap();
+        fs::create_dir_all(&answers_dir).unwrap();
+        fs::write(&input_path, "21\n").unwrap();
+        fs::write(answers_dir.join("day-99.txt"), "42\n\n").unwrap();
+
+        assert_eq!(
+            Some(answers_dir.join("day-99.txt")),
+            answers_path(&input_path)
+        );
+        assert_eq!(
+            [Some("42".to_string()), None],
+            expected_answers(&input_path)
+        );
+
+        let first = run_part(&solver, 1, &input_path, &[]).unwrap();
+        assert_eq!(Some("42".to_string()), first.answer);
+        assert_eq!(Status::Correct, first.status);
+
+        let second = run_part(&solver, 2, &input_path, &[]).unwrap();
+        assert_eq!(Status::Panicked, second.status);
+
+        let missing = run_part(&solver, 1, &root.join("inputs").join("nope.txt"), &[]).unwrap();
+        assert_eq!(Status::MissingInput, missing.status);
+
+        assert!(run_part(&solver, 3, &input_path, &[]).is_none());
+
+        // Streaming reads the same file differently but has to report it the same way
+        let streamed = stream_part(&solver, 1, &input_path, &[]).unwrap();
+        assert_eq!(
+            (first.answer, first.input_hash, Status::Correct, true),
+            (
+                streamed.answer,
+                streamed.input_hash,
+                streamed.status,
+                streamed.streamed
+            )
+        );
+        assert!(!first.streamed);
+
+        fs::write(answers_sidecar_path(&input_path), "\n7\n").unwrap();
+        assert_eq!([None, Some("7".to_string())], expected_answers(&input_path));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_run_part_with_cache() {
+        let root = std::env::temp_dir().join(format!("aoc-runner-cache-{}", std::process::id()));
+        let solver = Solver::of::<Doubler>();
+        let input_path = solver.input_path(&root);
+        fs::create_dir_all(solver.input_dir(&root)).unwrap();
+        fs::write(&input_path, "21\n").unwrap();
+        let fingerprints = Fingerprints {
+            shared: "helpers",
+            days: &[(2015, 99, "doubles")],
+        };
+        let cache = Cache::new(&root, fingerprints);
+
+        let first = run_part_with(&solver, 1, &input_path, &[], Some(&cache)).unwrap();
+        assert_eq!(
+            (Some("42".to_string()), false),
+            (first.answer, first.cached)
+        );
+        let second = run_part_with(&solver, 1, &input_path, &[], Some(&cache)).unwrap();
+        assert_eq!(
+            (Some("42".to_string()), true),
+            (second.answer, second.cached)
+        );
+        assert_eq!(first.input_hash, second.input_hash);
+
+        // Panics have no answer to cache, and new input needs solving again
+        let panicked = run_part_with(&solver, 2, &input_path, &[], Some(&cache)).unwrap();
+        let panicked_again = run_part_with(&solver, 2, &input_path, &[], Some(&cache)).unwrap();
+        assert_eq!(Status::Panicked, panicked_again.status);
+        assert!(!panicked.cached && !panicked_again.cached);
+        fs::write(&input_path, "5\n").unwrap();
+        let changed = run_part_with(&solver, 1, &input_path, &[], Some(&cache)).unwrap();
+        assert_eq!(
+            (Some("10".to_string()), false),
+            (changed.answer, changed.cached)
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    struct Scaler;
+
+    impl Puzzle for Scaler {
+        const YEAR: u32 = 2015;
+        const DAY: u32 = 98;
+        const PARAMS: &'static [Param] = &[Param::int("factor", 2)];
+
+        fn puzzle_1(contents: String) -> Answer {
+            (contents.trim().parse::<i64>().unwrap() * params::int::<i64>("factor")).into()
+        }
+
+        fn puzzle_2(contents: String) -> Answer {
+            Scaler::puzzle_1(contents)
+        }
+    }
+
+    #[test]
+    fn test_run_part_applies_params() {
+        let root = std::env::temp_dir().join(format!("aoc-runner-params-{}", std::process::id()));
+        let solver = Solver::of::<Scaler>();
+        let input_path = solver.input_path(&root);
+        fs::create_dir_all(solver.input_dir(&root)).unwrap();
+        fs::write(&input_path, "21\n").unwrap();
+
+        let report = run_part(&solver, 1, &input_path, &[]).unwrap();
+        assert_eq!(Some("42".to_string()), report.answer);
+        assert_eq!(Some(ParamValue::Int(2)), report.params.get("factor"));
+
+        fs::write(input_path.with_extension("params"), "factor=3\n").unwrap();
+        let report = run_part(&solver, 1, &input_path, &[]).unwrap();
+        assert_eq!(Some("63".to_string()), report.answer);
+
+        let overrides = [("factor".to_string(), "10".to_string())];
+        let report = run_part(&solver, 2, &input_path, &overrides).unwrap();
+        assert_eq!(Some("210".to_string()), report.answer);
+
+        let overrides = [("scale".to_string(), "10".to_string())];
+        let report = run_part(&solver, 1, &input_path, &overrides).unwrap();
+        assert_eq!(Status::InvalidParams, report.status);
+        assert_eq!(Some("no param named scale".to_string()), report.error);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    struct LaterDoubler;
+
+    impl Puzzle for LaterDoubler {
+        const YEAR: u32 = 2016;
+        const DAY: u32 = 1;
+
+        fn puzzle_1(contents: String) -> Answer {
+            Doubler::puzzle_1(contents)
+        }
+
+        fn puzzle_2(contents: String) -> Answer {
+            Doubler::puzzle_2(contents)
+        }
+    }
+
+    #[test]
+    fn test_registry_is_keyed_by_year_and_day() {
+        let mut registry = Registry::default();
+        registry.add::<LaterDoubler>();
+        registry.add::<Doubler>();
+
+        assert_eq!(vec![2015, 2016], registry.years());
+        assert_eq!(99, registry.get(2015, 99).unwrap().day);
+        assert!(registry.get(2016, 99).is_none());
+        assert_eq!(1, registry.year(2016).count());
+        let order = registry
+            .iter()
+            .map(|solver| (solver.year, solver.day))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(2015, 99), (2016, 1)], order);
+    }
+
+    #[test]
+    #[should_panic(expected = "registered twice")]
+    fn test_registry_rejects_duplicates() {
+        let mut registry = Registry::default();
+        registry.add::<Doubler>();
+        registry.add::<Doubler>();
+    }
+}
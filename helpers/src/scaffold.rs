@@ -0,0 +1,381 @@
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Where solver modules live relative to the workspace root
+const SOLVERS_DIR: &str = "binaries/src";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    InvalidDay(u32),
+    InvalidYear(u32),
+    AlreadyExists(PathBuf),
+    // A file we need to edit doesn't look like we expect
+    UnexpectedLayout(PathBuf),
+    Io(io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay(day) => write!(f, "day {day} isn't between 1 and 25"),
+            ScaffoldError::InvalidYear(year) => write!(f, "{year} has no advent of code"),
+            ScaffoldError::AlreadyExists(path) => {
+                write!(f, "{:?} already exists, refusing to overwrite it", path)
+            }
+            ScaffoldError::UnexpectedLayout(path) => {
+                write!(f, "couldn't find where to register the day in {:?}", path)
+            }
+            ScaffoldError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(err: io::Error) -> Self {
+        ScaffoldError::Io(err)
+    }
+}
+
+/// Where the solver module for a day lives, whether or not it's been created yet.
+pub fn module_path(root: &Path, year: u32, day: u32) -> PathBuf {
+    root.join(SOLVERS_DIR)
+        .join(format!("year_{year}"))
+        .join(format!("day_{day}.rs"))
+}
+
+/// Creates the solver module for a day, registers it with its year (creating the year if
+/// needed) and creates empty real and example inputs plus expected-answer stubs. Returns every
+/// file it created or edited.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+    if year < 2015 {
+        return Err(ScaffoldError::InvalidYear(year));
+    }
+
+    let src_dir = root.join(SOLVERS_DIR);
+    let module_path = module_path(root, year, day);
+    if module_path.exists() {
+        return Err(ScaffoldError::AlreadyExists(module_path));
+    }
+
+    // Worked out before anything's written so a year file we can't make sense of leaves the
+    // tree as it was
+    let year_path = src_dir.join(format!("year_{year}.rs"));
+    let (registration_path, registered, new_year) = if year_path.exists() {
+        let registered = register_day(&year_path, day)?;
+        (year_path, registered, false)
+    } else {
+        let lib_path = src_dir.join("lib.rs");
+        let registered = register_year(&lib_path, year)?;
+        (lib_path, registered, true)
+    };
+
+    // The module goes in before it's registered, so a failed write never leaves the year
+    // pointing at a module that isn't there
+    let mut created = Vec::new();
+    let result = write_new_files(root, year, day, new_year, &mut created).and_then(|()| {
+        fs::write(&registration_path, registered)?;
+        Ok(())
+    });
+    if let Err(err) = result {
+        for path in created.iter().rev() {
+            let _ = fs::remove_file(path);
+        }
+        return Err(err);
+    }
+
+    let mut touched = vec![registration_path];
+    touched.extend(created);
+    Ok(touched)
+}
+
+// Everything `new_day` creates rather than edits, each pushed to `created` once it's there
+fn write_new_files(
+    root: &Path,
+    year: u32,
+    day: u32,
+    new_year: bool,
+    created: &mut Vec<PathBuf>,
+) -> Result<(), ScaffoldError> {
+    if new_year {
+        let year_path = root.join(SOLVERS_DIR).join(format!("year_{year}.rs"));
+        fs::write(&year_path, year_template(day))?;
+        created.push(year_path);
+    }
+
+    let module_path = module_path(root, year, day);
+    fs::create_dir_all(
+        module_path
+            .parent()
+            .expect("module is always in a year dir"),
+    )?;
+    fs::write(&module_path, day_template(year, day))?;
+    created.push(module_path);
+
+    for dir in ["inputs", "answers"] {
+        let dir = root.join(dir).join(year.to_string());
+        fs::create_dir_all(&dir)?;
+        for name in [format!("day-{day}.txt"), format!("day-{day}-example.txt")] {
+            let path = dir.join(name);
+            // Inputs are often downloaded before starting, keep whatever is already there
+            if !path.exists() {
+                fs::write(&path, "")?;
+                created.push(path);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn day_template(year: u32, day: u32) -> String {
+    format!(
+        "use helpers::meta::Tag;
+use helpers::{{Answer, Puzzle}};
+
+pub struct Day{day};
+
+impl Puzzle for Day{day} {{
+    const YEAR: u32 = {year};
+    const DAY: u32 = {day};
+    const TITLE: &'static str = \"\";
+    const TAGS: &'static [Tag] = &[];
+    const HELPERS: &'static [&'static str] = &[];
+    const NOTES: &'static str = \"\";
+
+    fn puzzle_1(_contents: String) -> Answer {{
+        todo!()
+    }}
+
+    fn puzzle_2(_contents: String) -> Answer {{
+        todo!()
+    }}
+}}
+"
+    )
+}
+
+fn year_template(day: u32) -> String {
+    format!(
+        "use helpers::runner::Registry;
+
+pub mod day_{day};
+
+pub fn register(registry: &mut Registry) {{
+    registry.add::<day_{day}::Day{day}>();
+}}
+"
+    )
+}
+
+// The year file's contents with `day` registered
+fn register_day(year_path: &Path, day: u32) -> Result<String, ScaffoldError> {
+    let contents = fs::read_to_string(year_path)?;
+    insert_sorted(
+        &contents,
+        "pub mod day_",
+        ";",
+        &format!("pub mod day_{day};"),
+        day,
+    )
+    .and_then(|contents| {
+        insert_sorted(
+            &contents,
+            "    registry.add::<day_",
+            "::",
+            &format!("    registry.add::<day_{day}::Day{day}>();"),
+            day,
+        )
+    })
+    .ok_or_else(|| ScaffoldError::UnexpectedLayout(year_path.to_path_buf()))
+}
+
+// The lib's contents with `year` registered
+fn register_year(lib_path: &Path, year: u32) -> Result<String, ScaffoldError> {
+    let contents = fs::read_to_string(lib_path)?;
+    insert_sorted(
+        &contents,
+        "pub mod year_",
+        ";",
+        &format!("pub mod year_{year};"),
+        year,
+    )
+    .and_then(|contents| {
+        insert_sorted(
+            &contents,
+            "    year_",
+            "::register",
+            &format!("    year_{year}::register(&mut registry);"),
+            year,
+        )
+    })
+    .ok_or_else(|| ScaffoldError::UnexpectedLayout(lib_path.to_path_buf()))
+}
+
+/// Inserts `new_line` among the lines shaped like `<prefix><number><suffix>...`, keeping them
+/// in numeric order. Returns `None` when there are no such lines to anchor on.
+fn insert_sorted(
+    contents: &str,
+    prefix: &str,
+    suffix: &str,
+    new_line: &str,
+    key: u32,
+) -> Option<String> {
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    let keyed = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| {
+            let rest = line.strip_prefix(prefix)?;
+            let (number, _) = rest.split_once(suffix)?;
+            Some((idx, number.parse::<u32>().ok()?))
+        })
+        .collect::<Vec<_>>();
+    let (last_idx, _) = keyed.last()?;
+    let insert_at = keyed
+        .iter()
+        .find(|(_, existing)| *existing > key)
+        .map(|(idx, _)| *idx)
+        .unwrap_or(last_idx + 1);
+    lines.insert(insert_at, new_line);
+    let mut result = lines.join("\n");
+    result.push('\n');
+    Some(result)
+}
+
+#[cfg(test)]
+mod test_scaffold {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::scaffold::{insert_sorted, new_day, ScaffoldError};
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("binaries/src/year_2024")).unwrap();
+        fs::write(
+            root.join("binaries/src/lib.rs"),
+            "use helpers::runner::Registry;
+
+pub mod year_2024;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::default();
+    year_2024::register(&mut registry);
+    registry
+}
+",
+        )
+        .unwrap();
+        fs::write(
+            root.join("binaries/src/year_2024.rs"),
+            "use helpers::runner::Registry;
+
+pub mod day_1;
+pub mod day_3;
+
+pub fn register(registry: &mut Registry) {
+    registry.add::<day_1::Day1>();
+    registry.add::<day_3::Day3>();
+}
+",
+        )
+        .unwrap();
+        fs::write(root.join("binaries/src/year_2024/day_1.rs"), "").unwrap();
+        fs::write(root.join("binaries/src/year_2024/day_3.rs"), "").unwrap();
+        root
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let contents = "a\npub mod day_1;\npub mod day_10;\nb\n";
+        assert_eq!(
+            "a\npub mod day_1;\npub mod day_2;\npub mod day_10;\nb\n",
+            insert_sorted(contents, "pub mod day_", ";", "pub mod day_2;", 2).unwrap()
+        );
+        assert_eq!(
+            "a\npub mod day_1;\npub mod day_10;\npub mod day_11;\nb\n",
+            insert_sorted(contents, "pub mod day_", ";", "pub mod day_11;", 11).unwrap()
+        );
+        assert!(insert_sorted("a\nb\n", "pub mod day_", ";", "pub mod day_2;", 2).is_none());
+    }
+
+    #[test]
+    fn test_new_day_in_existing_year() {
+        let root = temp_root("existing");
+        let touched = new_day(&root, 2024, 2).unwrap();
+        assert!(touched.contains(&root.join("inputs/2024/day-2-example.txt")));
+        assert!(touched.contains(&root.join("answers/2024/day-2.txt")));
+
+        let year = fs::read_to_string(root.join("binaries/src/year_2024.rs")).unwrap();
+        assert!(year.contains("pub mod day_1;\npub mod day_2;\npub mod day_3;"));
+        assert!(year.contains(
+            "    registry.add::<day_1::Day1>();\n    registry.add::<day_2::Day2>();\n    registry.add::<day_3::Day3>();"
+        ));
+        let module = fs::read_to_string(root.join("binaries/src/year_2024/day_2.rs")).unwrap();
+        assert!(module.contains("impl Puzzle for Day2"));
+        assert!(module.contains("const YEAR: u32 = 2024;"));
+
+        assert!(matches!(
+            new_day(&root, 2024, 3),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_in_new_year() {
+        let root = temp_root("new-year");
+        fs::create_dir_all(root.join("inputs/2023")).unwrap();
+        fs::write(root.join("inputs/2023/day-5.txt"), "already downloaded").unwrap();
+
+        let touched = new_day(&root, 2023, 5).unwrap();
+        assert!(!touched.contains(&root.join("inputs/2023/day-5.txt")));
+        assert_eq!(
+            "already downloaded",
+            fs::read_to_string(root.join("inputs/2023/day-5.txt")).unwrap()
+        );
+
+        let lib = fs::read_to_string(root.join("binaries/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub mod year_2023;\npub mod year_2024;"));
+        assert!(lib.contains(
+            "    year_2023::register(&mut registry);\n    year_2024::register(&mut registry);"
+        ));
+        let year = fs::read_to_string(root.join("binaries/src/year_2023.rs")).unwrap();
+        assert!(year.contains("registry.add::<day_5::Day5>();"));
+
+        assert!(matches!(
+            new_day(&root, 2023, 26),
+            Err(ScaffoldError::InvalidDay(26))
+        ));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_registers_last() {
+        let root = temp_root("failed-write");
+        let lib_path = root.join("binaries/src/lib.rs");
+        let lib = fs::read_to_string(&lib_path).unwrap();
+        // A file where the year's directory should go makes writing the module fail
+        fs::write(root.join("binaries/src/year_2022"), "").unwrap();
+
+        assert!(matches!(new_day(&root, 2022, 1), Err(ScaffoldError::Io(_))));
+        assert_eq!(lib, fs::read_to_string(&lib_path).unwrap());
+        assert!(!root.join("binaries/src/year_2022.rs").exists());
+
+        let module = fs::read_to_string(
+            new_day(&root, 2024, 2)
+                .unwrap()
+                .into_iter()
+                .find(|path| path.ends_with("day_2.rs"))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(module.contains("const TAGS: &'static [Tag] = &[];"));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
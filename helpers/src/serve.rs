@@ -0,0 +1,478 @@
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::isolate::{self, ChildOptions, Limits};
+use crate::params::Params;
+use crate::runner::{Registry, Status};
+
+// Nobody needs this long to send a puzzle input, so stop waiting on slow clients
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_HEADERS: usize = 64;
+// Longest request or header line we'll read, the same as most servers allow
+const MAX_LINE_BYTES: usize = 8 * 1024;
+
+// Gives every request body its own file for the child to read
+static NEXT_BODY: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ServeConfig {
+    // How long a solve gets before its child is killed and the request gives up on it
+    pub time_limit: Duration,
+    pub max_body_bytes: usize,
+    // Connections past this many wait in the listen backlog until one finishes
+    pub max_connections: usize,
+}
+
+impl Default for ServeConfig {
+    fn default() -> Self {
+        ServeConfig {
+            time_limit: Duration::from_secs(30),
+            max_body_bytes: 1024 * 1024,
+            max_connections: 16,
+        }
+    }
+}
+
+/// A small HTTP/1.1 server answering puzzles from a `Registry`, each solve in a child process
+/// through `isolate::run_isolated`, so the executable has to handle `isolate::CHILD_COMMAND`:
+///
+/// - `POST /solve/{day}/{part}` solves the body, `?year=` picks the year (latest by default) and
+///   any other query pairs override the day's params
+/// - `GET /days` lists every registered day and its params
+/// - `GET /health`
+pub struct Server {
+    listener: TcpListener,
+    registry: Arc<Registry>,
+    config: ServeConfig,
+}
+
+impl Server {
+    pub fn bind(
+        addr: impl ToSocketAddrs,
+        registry: Registry,
+        config: ServeConfig,
+    ) -> io::Result<Self> {
+        Ok(Server {
+            listener: TcpListener::bind(addr)?,
+            registry: Arc::new(registry),
+            config,
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Handles connections until the listener fails, each on its own thread. Once
+    /// `max_connections` are open the rest wait to be accepted until one finishes.
+    pub fn run(&self) -> io::Result<()> {
+        let open = Arc::new((Mutex::new(0), Condvar::new()));
+        loop {
+            {
+                let (count, freed) = &*open;
+                let count = count.lock().expect("count is never poisoned");
+                let mut count = freed
+                    .wait_while(count, |count| *count >= self.config.max_connections)
+                    .expect("count is never poisoned");
+                *count += 1;
+            }
+            let slot = Slot(Arc::clone(&open));
+            let (stream, _) = self.listener.accept()?;
+            let registry = Arc::clone(&self.registry);
+            let config = self.config;
+            thread::spawn(move || {
+                let _slot = slot;
+                // The client going away mid-response isn't something we can do anything about
+                let _ = handle(stream, &registry, config);
+            });
+        }
+    }
+}
+
+// Counts a connection as open until its thread is done with it
+struct Slot(Arc<(Mutex<usize>, Condvar)>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        let (count, freed) = &*self.0;
+        *count.lock().expect("count is never poisoned") -= 1;
+        freed.notify_one();
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+struct Response {
+    status: u16,
+    body: Value,
+}
+
+impl Response {
+    fn ok(body: Value) -> Self {
+        Response { status: 200, body }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Response {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+}
+
+fn handle(stream: TcpStream, registry: &Registry, config: ServeConfig) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let response = match read_request(&mut reader, config.max_body_bytes) {
+        Ok(request) => route(request, registry, config),
+        Err(response) => response,
+    };
+    write_response(stream, response)
+}
+
+fn read_request(reader: &mut impl BufRead, max_body_bytes: usize) -> Result<Request, Response> {
+    let line = read_line(reader, "the request")?;
+    let mut request_line = line.split_whitespace();
+    let (Some(method), Some(target), Some(_version)) = (
+        request_line.next(),
+        request_line.next(),
+        request_line.next(),
+    ) else {
+        return Err(Response::error(400, "malformed request line"));
+    };
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let query = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (key.to_string(), value.to_string())
+        })
+        .collect();
+    let mut request = Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        query,
+        body: Vec::new(),
+    };
+
+    let mut content_length = 0;
+    for _ in 0..=MAX_HEADERS {
+        let header = read_line(reader, "headers")?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            if content_length > max_body_bytes {
+                return Err(Response::error(
+                    413,
+                    format!("body is over the {max_body_bytes} byte limit"),
+                ));
+            }
+            request.body = vec![0; content_length];
+            reader
+                .read_exact(&mut request.body)
+                .map_err(|_| Response::error(408, "timed out reading the body"))?;
+            return Ok(request);
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| Response::error(400, "bad content-length"))?;
+            }
+        }
+    }
+    Err(Response::error(431, "too many headers"))
+}
+
+// One line of the request head, refusing to buffer any more than `MAX_LINE_BYTES` of it
+fn read_line(reader: &mut impl BufRead, what: &str) -> Result<String, Response> {
+    let mut line = String::new();
+    reader
+        .take(MAX_LINE_BYTES as u64 + 1)
+        .read_line(&mut line)
+        .map_err(|_| Response::error(408, format!("timed out reading {what}")))?;
+    if line.len() > MAX_LINE_BYTES {
+        return Err(Response::error(
+            431,
+            format!("a line of {what} is over the {MAX_LINE_BYTES} byte limit"),
+        ));
+    }
+    Ok(line)
+}
+
+fn route(request: Request, registry: &Registry, config: ServeConfig) -> Response {
+    let segments = request
+        .path
+        .trim_matches('/')
+        .split('/')
+        .collect::<Vec<_>>();
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["health"]) => Response::ok(json!({ "status": "ok" })),
+        ("GET", ["days"]) => days(registry),
+        ("POST", ["solve", day, part]) => solve(registry, config, day, part, &request),
+        (_, ["health"] | ["days"] | ["solve", _, _]) => {
+            Response::error(405, format!("{} isn't allowed here", request.method))
+        }
+        _ => Response::error(404, format!("nothing at {}", request.path)),
+    }
+}
+
+fn days(registry: &Registry) -> Response {
+    let days = registry
+        .iter()
+        .map(|solver| {
+            json!({
+                "year": solver.year,
+                "day": solver.day,
+                "variant": solver.variant,
+                "params": Params::defaults(solver.params),
+            })
+        })
+        .collect::<Vec<_>>();
+    Response::ok(json!({ "days": days }))
+}
+
+fn solve(
+    registry: &Registry,
+    config: ServeConfig,
+    day: &str,
+    part: &str,
+    request: &Request,
+) -> Response {
+    let (Ok(day), Ok(part)) = (day.parse::<u32>(), part.parse::<u8>()) else {
+        return Response::error(400, "day and part have to be numbers");
+    };
+    if !(1..=2).contains(&part) {
+        return Response::error(404, format!("there is no part {part}"));
+    }
+
+    let mut overrides = Vec::new();
+    let mut year = None;
+    for (key, value) in request.query.iter() {
+        if key == "year" {
+            match value.parse::<u32>() {
+                Ok(value) => year = Some(value),
+                Err(_) => return Response::error(400, "year has to be a number"),
+            }
+        } else {
+            overrides.push((key.clone(), value.clone()));
+        }
+    }
+    let Some(year) = year.or_else(|| registry.years().last().copied()) else {
+        return Response::error(404, "no solvers registered");
+    };
+    let Some(solver) = registry.get(year, day).copied() else {
+        return Response::error(404, format!("no solver registered for {year} day {day}"));
+    };
+
+    let mut params = Params::defaults(solver.params);
+    if let Err(err) = params.apply(&overrides) {
+        return Response::error(400, err.to_string());
+    }
+
+    if std::str::from_utf8(&request.body).is_err() {
+        return Response::error(400, "input has to be utf-8");
+    }
+
+    // A directory of its own so the child can't pick up sidecar files meant for something else
+    let dir = env::temp_dir().join(format!(
+        "aoc-serve-{}-{}",
+        process::id(),
+        NEXT_BODY.fetch_add(1, Ordering::Relaxed)
+    ));
+    let input_path = dir.join("input.txt");
+    if let Err(err) = fs::create_dir_all(&dir).and_then(|()| fs::write(&input_path, &request.body))
+    {
+        let _ = fs::remove_dir_all(&dir);
+        return Response::error(500, format!("couldn't hand the input to the solver: {err}"));
+    }
+    // The child is killed at the limit, so nothing's left running once the request gives up
+    let options = ChildOptions {
+        limits: Limits {
+            time: Some(config.time_limit),
+            memory: None,
+        },
+        ..ChildOptions::default()
+    };
+    let report = isolate::run_isolated(&solver, part, &input_path, &overrides, options);
+    let _ = fs::remove_dir_all(&dir);
+
+    match report {
+        Some(report) if report.status == Status::TimedOut => Response::error(
+            504,
+            format!(
+                "{year} day {day} part {part} didn't finish within {:.3} s",
+                config.time_limit.as_secs_f64()
+            ),
+        ),
+        Some(mut report) => {
+            report.input = "request body".to_string();
+            Response::ok(serde_json::to_value(report).expect("report is always serializable"))
+        }
+        None => Response::error(404, format!("there is no part {part}")),
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        413 => "Content Too Large",
+        431 => "Request Header Fields Too Large",
+        504 => "Gateway Timeout",
+        _ => "Internal Server Error",
+    }
+}
+
+fn write_response(mut stream: TcpStream, response: Response) -> io::Result<()> {
+    let body = response.body.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        response.status,
+        reason(response.status),
+        body.len()
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod test_serve {
+    use std::io::{Read, Write};
+    use std::net::{SocketAddr, TcpStream};
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    use serde_json::Value;
+
+    use crate::params::{self, Param};
+    use crate::runner::Registry;
+    use crate::serve::{ServeConfig, Server, MAX_LINE_BYTES};
+    use crate::{Answer, Puzzle};
+
+    // Solving happens in a child `aoc`, which the test binary can't stand in for, so these only
+    // go as far as a solve would start. `binaries/tests/serve.rs` covers the rest
+    struct Multiplier;
+
+    impl Puzzle for Multiplier {
+        const YEAR: u32 = 2015;
+        const DAY: u32 = 1;
+        const PARAMS: &'static [Param] = &[Param::int("factor", 2)];
+
+        fn puzzle_1(contents: String) -> Answer {
+            (contents.trim().parse::<i64>().unwrap() * params::int::<i64>("factor")).into()
+        }
+
+        fn puzzle_2(contents: String) -> Answer {
+            Multiplier::puzzle_1(contents)
+        }
+    }
+
+    fn start(max_connections: usize) -> SocketAddr {
+        let mut registry = Registry::default();
+        registry.add::<Multiplier>();
+        let config = ServeConfig {
+            time_limit: Duration::from_millis(200),
+            max_body_bytes: 16,
+            max_connections,
+        };
+        let server = Server::bind("127.0.0.1:0", registry, config).unwrap();
+        let addr = server.local_addr().unwrap();
+        thread::spawn(move || server.run());
+        addr
+    }
+
+    fn send(addr: SocketAddr, raw: &str) -> (u16, Value) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(raw.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    fn request(addr: SocketAddr, method: &str, target: &str, body: &str) -> (u16, Value) {
+        send(
+            addr,
+            &format!(
+                "{method} {target} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            ),
+        )
+    }
+
+    #[test]
+    fn test_health_and_days() {
+        let addr = start(4);
+        let (status, body) = request(addr, "GET", "/health", "");
+        assert_eq!(200, status);
+        assert_eq!("ok", body["status"]);
+
+        let (status, body) = request(addr, "GET", "/days", "");
+        assert_eq!(200, status);
+        assert_eq!(1, body["days"].as_array().unwrap().len());
+        assert_eq!(2015, body["days"][0]["year"]);
+        assert_eq!(2, body["days"][0]["params"]["factor"]);
+
+        assert_eq!(405, request(addr, "POST", "/health", "").0);
+        assert_eq!(404, request(addr, "GET", "/nope", "").0);
+    }
+
+    #[test]
+    fn test_solve_rejects_bad_requests() {
+        let addr = start(4);
+        assert_eq!(400, request(addr, "POST", "/solve/1/1?scale=3", "21").0);
+        assert_eq!(400, request(addr, "POST", "/solve/1/1?year=now", "21").0);
+        assert_eq!(404, request(addr, "POST", "/solve/2/1", "21").0);
+        assert_eq!(404, request(addr, "POST", "/solve/1/3", "21").0);
+        assert_eq!(405, request(addr, "GET", "/solve/1/1", "").0);
+    }
+
+    #[test]
+    fn test_request_limits() {
+        let addr = start(4);
+        let (status, body) = request(addr, "POST", "/solve/1/1", "more than sixteen bytes");
+        assert_eq!(413, status);
+        assert!(body["error"].as_str().unwrap().contains("16 byte"));
+
+        let long_header = "x".repeat(MAX_LINE_BYTES + 1);
+        let (status, _) = send(
+            addr,
+            &format!("GET /health HTTP/1.1\r\nX-Long: {long_header}\r\n\r\n"),
+        );
+        assert_eq!(431, status);
+    }
+
+    #[test]
+    fn test_connection_limit() {
+        let addr = start(1);
+        // Holds the only slot by never finishing its request
+        let idle = TcpStream::connect(addr).unwrap();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || sender.send(request(addr, "GET", "/health", "").0));
+        assert!(receiver.recv_timeout(Duration::from_millis(200)).is_err());
+
+        drop(idle);
+        assert_eq!(Ok(200), receiver.recv_timeout(Duration::from_secs(5)));
+    }
+}
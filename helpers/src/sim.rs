@@ -0,0 +1,256 @@
+use crate::cycle::{self, Cycle};
+
+/// A discrete simulation split into the rules, which never change, and the `State` they move
+/// forward one step at a time. Implementing it only takes `step`, `Driver` does the rest.
+pub trait Simulation {
+    type State: Clone;
+
+    /// Moves `state` on by one step.
+    fn step(&self, state: &mut Self::State);
+
+    /// Whether `state` is finished and shouldn't be stepped any further.
+    fn is_done(&self, _state: &Self::State) -> bool {
+        false
+    }
+}
+
+impl<S: Simulation> Simulation for &S {
+    type State = S::State;
+
+    fn step(&self, state: &mut Self::State) {
+        (*self).step(state)
+    }
+
+    fn is_done(&self, state: &Self::State) -> bool {
+        (*self).is_done(state)
+    }
+}
+
+type OnStep<'a, State> = Box<dyn FnMut(usize, &State) + 'a>;
+
+/// Runs a `Simulation`, counting steps and optionally keeping a snapshot every so often so it
+/// can be rewound to any earlier step without replaying from the start.
+pub struct Driver<'a, S: Simulation> {
+    sim: S,
+    state: S::State,
+    steps: usize,
+    snapshot_every: Option<usize>,
+    // Snapshot `idx` is the state at step `idx * snapshot_every`, the start is always kept
+    snapshots: Vec<S::State>,
+    on_step: Option<OnStep<'a, S::State>>,
+}
+
+impl<'a, S: Simulation> Driver<'a, S> {
+    pub fn new(sim: S, start: S::State) -> Self {
+        Driver {
+            sim,
+            snapshots: vec![start.clone()],
+            state: start,
+            steps: 0,
+            snapshot_every: None,
+            on_step: None,
+        }
+    }
+
+    /// Keeps a copy of the state every `every` steps. Without this `rewind` replays from the
+    /// start.
+    pub fn snapshot_every(mut self, every: usize) -> Self {
+        assert!(every > 0, "snapshots have to be at least a step apart");
+        self.snapshot_every = Some(every);
+        self
+    }
+
+    /// Calls `f` with the step number and state after every step, for drawing or collecting
+    /// stats as it goes. Not called for steps replayed by `rewind`.
+    pub fn on_step(mut self, f: impl FnMut(usize, &S::State) + 'a) -> Self {
+        self.on_step = Some(Box::new(f));
+        self
+    }
+
+    pub fn sim(&self) -> &S {
+        &self.sim
+    }
+
+    pub fn state(&self) -> &S::State {
+        &self.state
+    }
+
+    pub fn into_state(self) -> S::State {
+        self.state
+    }
+
+    /// How many steps have been taken to get to the current state.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.sim.is_done(&self.state)
+    }
+
+    fn advance(&mut self) {
+        self.sim.step(&mut self.state);
+        self.steps += 1;
+        if let Some(every) = self.snapshot_every {
+            if self.steps.is_multiple_of(every) && self.snapshots.len() == self.steps / every {
+                self.snapshots.push(self.state.clone());
+            }
+        }
+    }
+
+    /// Takes one step, unless the simulation is done. Returns whether it stepped.
+    pub fn step(&mut self) -> bool {
+        if self.is_done() {
+            return false;
+        }
+        self.advance();
+        if let Some(on_step) = self.on_step.as_mut() {
+            on_step(self.steps, &self.state);
+        }
+        true
+    }
+
+    /// Takes up to `n` steps, stopping early if the simulation finishes. Returns how many it
+    /// took.
+    pub fn run(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.step()).count()
+    }
+
+    /// Steps until `done` holds for the state, checking the current state first. Returns false
+    /// if the simulation finished before that happened.
+    pub fn run_until(&mut self, mut done: impl FnMut(&S::State) -> bool) -> bool {
+        loop {
+            if done(&self.state) {
+                return true;
+            }
+            if !self.step() {
+                return false;
+            }
+        }
+    }
+
+    /// Steps until the simulation says it's done. Never returns if it doesn't finish.
+    pub fn run_to_end(&mut self) -> usize {
+        let start = self.steps;
+        while self.step() {}
+        self.steps - start
+    }
+
+    /// Goes back to the state at `step`, starting from the closest snapshot before it. Anything
+    /// after `step` is forgotten, so stepping on from there redoes it.
+    pub fn rewind(&mut self, step: usize) {
+        assert!(
+            step <= self.steps,
+            "can't rewind to step {step}, only at {}",
+            self.steps
+        );
+        let every = self.snapshot_every.unwrap_or(usize::MAX);
+        let snapshot = (step / every).min(self.snapshots.len() - 1);
+        self.snapshots.truncate(snapshot + 1);
+        self.state = self.snapshots[snapshot].clone();
+        self.steps = snapshot.saturating_mul(every).min(step);
+        while self.steps < step {
+            self.advance();
+        }
+    }
+}
+
+impl<S: Simulation> Driver<'_, S>
+where
+    S::State: PartialEq,
+{
+    /// Where the simulation starts repeating from the current state on, using Brent's algorithm.
+    /// Ignores `is_done`, so finished states have to step to themselves to count as a cycle.
+    pub fn find_cycle(&self) -> Cycle {
+        cycle::brent(&self.state, |state| {
+            let mut next = state.clone();
+            self.sim.step(&mut next);
+            next
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_sim {
+    use crate::cycle::Cycle;
+    use crate::sim::{Driver, Simulation};
+
+    // Collatz, finished once it reaches 1
+    struct Collatz;
+
+    impl Simulation for Collatz {
+        type State = u64;
+
+        fn step(&self, state: &mut u64) {
+            *state = if state.is_multiple_of(2) {
+                *state / 2
+            } else {
+                *state * 3 + 1
+            };
+        }
+
+        fn is_done(&self, state: &u64) -> bool {
+            *state == 1
+        }
+    }
+
+    #[test]
+    fn test_run_and_run_until() {
+        let mut seen = Vec::new();
+        let mut driver = Driver::new(Collatz, 6).on_step(|step, state| seen.push((step, *state)));
+        assert_eq!(2, driver.run(2));
+        assert_eq!(10, *driver.state());
+        assert!(driver.run_until(|state| *state == 16));
+        assert_eq!(4, driver.steps());
+        // 16, 8, 4, 2, 1 and then it stops
+        assert_eq!(4, driver.run(100));
+        assert!(driver.is_done() && !driver.step());
+        assert!(!driver.run_until(|state| *state == 7));
+        drop(driver);
+        assert_eq!(vec![(1, 3), (2, 10), (3, 5), (4, 16)], seen[..4]);
+        assert_eq!(8, seen.len());
+    }
+
+    #[test]
+    fn test_rewind() {
+        let mut plain = Driver::new(Collatz, 27);
+        let trajectory = (0..=60)
+            .map(|_| {
+                let state = *plain.state();
+                plain.step();
+                state
+            })
+            .collect::<Vec<_>>();
+
+        for every in [None, Some(1), Some(7)] {
+            let mut driver = Driver::new(Collatz, 27);
+            if let Some(every) = every {
+                driver = driver.snapshot_every(every);
+            }
+            driver.run(60);
+            for step in [59, 43, 43, 20, 0] {
+                driver.rewind(step);
+                assert_eq!((step, trajectory[step]), (driver.steps(), *driver.state()));
+            }
+            // Rewinding forgets the future, so running on has to redo it the same way
+            driver.run(30);
+            assert_eq!(trajectory[30], *driver.state());
+            driver.rewind(25);
+            assert_eq!(trajectory[25], *driver.state());
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        // Left to run past 1 Collatz goes round 1, 4, 2
+        let mut driver = Driver::new(Collatz, 6);
+        driver.run(3);
+        assert_eq!(
+            Cycle {
+                lead_in: 3,
+                length: 3
+            },
+            driver.find_cycle()
+        );
+    }
+}
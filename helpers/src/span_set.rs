@@ -0,0 +1,277 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
+
+use crate::num::Int;
+
+/// A set of integers stored as half-open spans, kept disjoint and merged so touching or
+/// overlapping inserts become one span. Spans are also indexed by length so `first_fit` doesn't
+/// have to walk them all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanSet<T = i64> {
+    // start -> end
+    spans: BTreeMap<T, T>,
+    // length -> starts of the spans that long
+    by_length: BTreeMap<T, BTreeSet<T>>,
+}
+
+impl<T: Int> Default for SpanSet<T> {
+    fn default() -> Self {
+        SpanSet {
+            spans: BTreeMap::new(),
+            by_length: BTreeMap::new(),
+        }
+    }
+}
+
+fn length<T: Int>(start: T, end: T) -> T {
+    end.checked_sub(start).expect("span lengths have to fit")
+}
+
+impl<T: Int> SpanSet<T> {
+    pub fn new() -> Self {
+        SpanSet::default()
+    }
+
+    fn add_span(&mut self, start: T, end: T) {
+        self.spans.insert(start, end);
+        self.by_length
+            .entry(length(start, end))
+            .or_default()
+            .insert(start);
+    }
+
+    fn remove_span(&mut self, start: T) -> T {
+        let end = self.spans.remove(&start).expect("span has to exist");
+        let len = length(start, end);
+        let starts = self
+            .by_length
+            .get_mut(&len)
+            .expect("span has to be indexed");
+        starts.remove(&start);
+        if starts.is_empty() {
+            self.by_length.remove(&len);
+        }
+        end
+    }
+
+    // The span holding `point`, if any
+    fn span_at(&self, point: T) -> Option<Range<T>> {
+        self.spans
+            .range(..=point)
+            .next_back()
+            .filter(|(_, end)| point < **end)
+            .map(|(start, end)| *start..*end)
+    }
+
+    /// Adds every point in `range`, merging with any span it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = (range.start, range.end);
+        // Anything starting at or before `end` that reaches `start` gets swallowed
+        let touching = self
+            .spans
+            .range(..=end)
+            .rev()
+            .take_while(|(_, span_end)| **span_end >= start)
+            .map(|(span_start, _)| *span_start)
+            .collect::<Vec<_>>();
+        for span_start in touching {
+            let span_end = self.remove_span(span_start);
+            start = start.min(span_start);
+            end = end.max(span_end);
+        }
+        self.add_span(start, end);
+    }
+
+    /// Removes every point in `range`, splitting any span that sticks out either side.
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let overlapping = self
+            .spans
+            .range(..range.end)
+            .rev()
+            .take_while(|(_, span_end)| **span_end > range.start)
+            .map(|(span_start, _)| *span_start)
+            .collect::<Vec<_>>();
+        for span_start in overlapping {
+            let span_end = self.remove_span(span_start);
+            if span_start < range.start {
+                self.add_span(span_start, range.start);
+            }
+            if range.end < span_end {
+                self.add_span(range.end, span_end);
+            }
+        }
+    }
+
+    /// Adds everything in `other`.
+    pub fn merge(&mut self, other: &SpanSet<T>) {
+        for span in other.iter() {
+            self.insert(span);
+        }
+    }
+
+    /// Splits off everything at or after `at` into a new set, cutting a span that crosses it.
+    pub fn split_off(&mut self, at: T) -> SpanSet<T> {
+        let mut after = SpanSet::new();
+        if let Some(crossing) = self.span_at(at).filter(|span| span.start < at) {
+            self.remove_span(crossing.start);
+            self.add_span(crossing.start, at);
+            after.add_span(at, crossing.end);
+        }
+        let moving = self
+            .spans
+            .range(at..)
+            .map(|(start, _)| *start)
+            .collect::<Vec<_>>();
+        for start in moving {
+            let end = self.remove_span(start);
+            after.add_span(start, end);
+        }
+        after
+    }
+
+    pub fn contains(&self, point: T) -> bool {
+        self.span_at(point).is_some()
+    }
+
+    /// The lowest `len` points that sit in a single span and all come before `before`. Looks at
+    /// the first span of each length at least `len`, so it's `O(log n)` for each distinct length.
+    pub fn first_fit(&self, len: T, before: T) -> Option<Range<T>> {
+        let start = self
+            .by_length
+            .range(len..)
+            .filter_map(|(_, starts)| starts.first())
+            .min()?;
+        let end = start.checked_add(len)?;
+        (end <= before).then_some(*start..end)
+    }
+
+    /// The lowest span.
+    pub fn first(&self) -> Option<Range<T>> {
+        self.spans
+            .first_key_value()
+            .map(|(start, end)| *start..*end)
+    }
+
+    /// The spans in order.
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.spans.iter().map(|(start, end)| *start..*end)
+    }
+
+    /// How many spans, not how many points.
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test_span_set {
+    use std::collections::BTreeSet;
+    use std::ops::Range;
+
+    use crate::span_set::SpanSet;
+
+    fn spans(set: &SpanSet) -> Vec<Range<i64>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let mut set = SpanSet::new();
+        set.insert(10..12);
+        set.insert(0..3);
+        set.insert(5..7);
+        assert_eq!(vec![0..3, 5..7, 10..12], spans(&set));
+
+        // Touching counts, so 3..5 joins both sides
+        set.insert(3..5);
+        assert_eq!(vec![0..7, 10..12], spans(&set));
+        set.insert(-2..11);
+        assert_eq!(vec![-2..12], spans(&set));
+        set.insert(4..4);
+        assert_eq!(1, set.len());
+    }
+
+    #[test]
+    fn test_remove_splits() {
+        let mut set = SpanSet::new();
+        set.insert(0..10);
+        set.insert(20..30);
+        set.remove(5..25);
+        assert_eq!(vec![0..5, 25..30], spans(&set));
+        set.remove(2..3);
+        assert_eq!(vec![0..2, 3..5, 25..30], spans(&set));
+        assert!(set.contains(4) && !set.contains(2) && !set.contains(5));
+        set.remove(-5..100);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_split_off_and_merge() {
+        let mut set = SpanSet::new();
+        set.insert(0..10);
+        set.insert(15..20);
+        let after = set.split_off(5);
+        assert_eq!(vec![0..5], spans(&set));
+        assert_eq!(vec![5..10, 15..20], spans(&after));
+        set.merge(&after);
+        assert_eq!(vec![0..10, 15..20], spans(&set));
+    }
+
+    #[test]
+    fn test_first_fit() {
+        let mut set = SpanSet::new();
+        set.insert(2..4);
+        set.insert(6..7);
+        set.insert(9..14);
+        set.insert(20..25);
+        assert_eq!(Some(2..4), set.first_fit(2, 100));
+        assert_eq!(Some(2..3), set.first_fit(1, 100));
+        assert_eq!(Some(9..12), set.first_fit(3, 100));
+        assert_eq!(Some(9..12), set.first_fit(3, 12));
+        assert_eq!(None, set.first_fit(3, 11));
+        assert_eq!(None, set.first_fit(6, 100));
+    }
+
+    // Every operation checked against a plain set of points
+    #[test]
+    fn test_against_point_set() {
+        let mut set = SpanSet::new();
+        let mut points = BTreeSet::new();
+        let mut seed = 7u64;
+        for _ in 0..2_000 {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            let start = (seed >> 33) as i64 % 60;
+            let len = (seed >> 20) as i64 % 8;
+            if seed.is_multiple_of(3) {
+                set.remove(start..start + len);
+                (start..start + len).for_each(|point| {
+                    points.remove(&point);
+                });
+            } else {
+                set.insert(start..start + len);
+                points.extend(start..start + len);
+            }
+            let expanded = set.iter().flatten().collect::<BTreeSet<_>>();
+            assert_eq!(points, expanded);
+            // Merged means no two spans touch
+            assert!(set
+                .iter()
+                .zip(set.iter().skip(1))
+                .all(|(a, b)| a.end < b.start));
+
+            let fit =
+                (0..60).find(|start| (*start..start + len.max(1)).all(|p| points.contains(&p)));
+            assert_eq!(fit, set.first_fit(len.max(1), 100).map(|fit| fit.start));
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::io::BufRead;
+use std::ops::Range;
+
+// Upper bound on how much `find_all` takes from the reader at once
+const CHUNK: usize = 64 * 1024;
+
+/// The input's lines without their endings. Panics if it can't be read or isn't utf-8, same as
+/// the runner does for input it reads up front.
+pub fn lines(input: &mut dyn BufRead) -> impl Iterator<Item = String> + '_ {
+    input
+        .lines()
+        .map(|line| line.expect("input has to be readable utf-8"))
+}
+
+/// Everything left in the input as one string.
+pub fn read_all(input: &mut dyn BufRead) -> String {
+    let mut contents = String::new();
+    input
+        .read_to_string(&mut contents)
+        .expect("input has to be readable utf-8");
+    contents
+}
+
+/// Calls `on_match` with every match in the input, reading it a chunk at a time so only about
+/// a chunk is held at once. `find(haystack, from)` returns the first match starting at or after
+/// `from`, like `regex::bytes::Regex::find_at`.
+///
+/// Matches can't be longer than `longest_match` bytes. Anything that ends within that distance of
+/// the end of what's been read so far is held back until more arrives, since it could still grow
+/// or turn out to start a longer match. `find` shouldn't look at anything before `from`, as bytes
+/// before it may have been thrown away.
+pub fn find_all(
+    input: &mut dyn BufRead,
+    longest_match: usize,
+    mut find: impl FnMut(&[u8], usize) -> Option<Range<usize>>,
+    mut on_match: impl FnMut(&[u8]),
+) {
+    let mut buffer = Vec::new();
+    loop {
+        let chunk = input.fill_buf().expect("input has to be readable");
+        let at_end = chunk.is_empty();
+        let taken = chunk.len().min(CHUNK);
+        buffer.extend_from_slice(&chunk[..taken]);
+        input.consume(taken);
+
+        let settled = if at_end {
+            buffer.len()
+        } else {
+            buffer.len().saturating_sub(longest_match)
+        };
+        let mut from = 0;
+        let mut unsettled = None;
+        while let Some(found) = find(&buffer, from) {
+            if found.end > settled {
+                unsettled = Some(found.start);
+                break;
+            }
+            on_match(&buffer[found.clone()]);
+            from = if found.is_empty() {
+                found.end + 1
+            } else {
+                found.end
+            };
+        }
+        if at_end {
+            return;
+        }
+        // Anything a match could still start in has to stay for the next round
+        let keep_from = unsettled.unwrap_or(usize::MAX).min(from.max(settled));
+        buffer.drain(..keep_from.min(buffer.len()));
+    }
+}
+
+#[cfg(test)]
+mod test_stream {
+    use std::io::BufReader;
+    use std::ops::Range;
+
+    use crate::stream::{find_all, lines};
+
+    // An x followed by as many as 3 digits, taking all of them
+    fn find_code(haystack: &[u8], from: usize) -> Option<Range<usize>> {
+        (from..haystack.len()).find_map(|start| {
+            let digits = haystack[start + 1..]
+                .iter()
+                .take(3)
+                .take_while(|byte| byte.is_ascii_digit())
+                .count();
+            (haystack[start] == b'x' && digits > 0).then_some(start..start + 1 + digits)
+        })
+    }
+
+    #[test]
+    fn test_find_all_across_chunks() {
+        let text = b"x1 ax12x123x1234 xx9x 0x98";
+        let mut expected = Vec::new();
+        find_all(&mut &text[..], 4, find_code, |found| {
+            expected.push(found.to_vec())
+        });
+        assert_eq!(
+            ["x1", "x12", "x123", "x123", "x9", "x98"]
+                .map(|code| code.as_bytes().to_vec())
+                .to_vec(),
+            expected
+        );
+
+        // Tiny reads split matches every way they can be split
+        for capacity in 1..6 {
+            let mut found = Vec::new();
+            let mut input = BufReader::with_capacity(capacity, &text[..]);
+            find_all(&mut input, 4, find_code, |code| found.push(code.to_vec()));
+            assert_eq!(expected, found, "reading {capacity} bytes at a time");
+        }
+    }
+
+    #[test]
+    fn test_lines() {
+        let mut input = BufReader::with_capacity(2, &b"3   4\r\n4   3\n\n9"[..]);
+        assert_eq!(
+            vec!["3   4", "4   3", "", "9"],
+            lines(&mut input).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use crate::hash::FastMap;
+
+const ROOT: usize = 0;
+
+/// Where one pattern was found, `text[start..end]`, with `pattern` being the id `insert` gave it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match {
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Patterns sharing prefixes stored along shared paths, so every pattern starting at a position
+/// can be found in one walk down from the root instead of one probe per pattern.
+#[derive(Debug, Clone)]
+pub struct Trie<T> {
+    children: Vec<FastMap<T, usize>>,
+    // The pattern ending at each node, if any
+    terminal: Vec<Option<usize>>,
+    // Indexed by pattern id
+    lengths: Vec<usize>,
+}
+
+impl<T: Hash + Eq + Clone> Default for Trie<T> {
+    fn default() -> Self {
+        Trie {
+            children: vec![FastMap::default()],
+            terminal: vec![None],
+            lengths: Vec::new(),
+        }
+    }
+}
+
+impl<T: Hash + Eq + Clone> Trie<T> {
+    pub fn new() -> Self {
+        Trie::default()
+    }
+
+    /// Adds `pattern` and returns its id. Ids count up from 0, adding a pattern twice gives back
+    /// the id it got the first time.
+    pub fn insert(&mut self, pattern: impl IntoIterator<Item = T>) -> usize {
+        let mut node = ROOT;
+        let mut len = 0;
+        for symbol in pattern {
+            node = match self.children[node].get(&symbol) {
+                Some(child) => *child,
+                None => {
+                    let child = self.children.len();
+                    self.children.push(FastMap::default());
+                    self.terminal.push(None);
+                    self.children[node].insert(symbol, child);
+                    child
+                }
+            };
+            len += 1;
+        }
+        *self.terminal[node].get_or_insert_with(|| {
+            self.lengths.push(len);
+            self.lengths.len() - 1
+        })
+    }
+
+    /// How many distinct patterns there are.
+    pub fn len(&self) -> usize {
+        self.lengths.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lengths.is_empty()
+    }
+
+    pub fn pattern_len(&self, pattern: usize) -> usize {
+        self.lengths[pattern]
+    }
+
+    pub fn contains(&self, pattern: &[T]) -> bool {
+        let mut node = ROOT;
+        for symbol in pattern {
+            match self.children[node].get(symbol) {
+                Some(child) => node = *child,
+                None => return false,
+            }
+        }
+        self.terminal[node].is_some()
+    }
+
+    /// Every pattern that `text[start..]` starts with, shortest first.
+    pub fn matches_at<'a>(
+        &'a self,
+        text: &'a [T],
+        start: usize,
+    ) -> impl Iterator<Item = Match> + 'a {
+        let mut node = ROOT;
+        text[start..]
+            .iter()
+            .map_while(move |symbol| {
+                node = *self.children[node].get(symbol)?;
+                Some(node)
+            })
+            .zip(start + 1..)
+            .filter_map(move |(node, end)| {
+                self.terminal[node].map(|pattern| Match {
+                    pattern,
+                    start,
+                    end,
+                })
+            })
+    }
+}
+
+/// A `Trie` with failure links added, so every match of every pattern across a whole text comes
+/// out of a single pass over it.
+#[derive(Debug, Clone)]
+pub struct AhoCorasick<T> {
+    trie: Trie<T>,
+    // The node for the longest proper suffix of a node's path that's also in the trie
+    fail: Vec<usize>,
+    // Every pattern ending at a node, including ones only reached through failure links
+    outputs: Vec<Vec<usize>>,
+}
+
+impl<T: Hash + Eq + Clone> AhoCorasick<T> {
+    pub fn new(trie: Trie<T>) -> Self {
+        let nodes = trie.children.len();
+        let mut fail = vec![ROOT; nodes];
+        let mut outputs = trie
+            .terminal
+            .iter()
+            .map(|pattern| pattern.iter().copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        // Breadth first so a node's failure link is always settled before its children need it
+        let mut queue = trie.children[ROOT]
+            .values()
+            .copied()
+            .collect::<VecDeque<_>>();
+        while let Some(node) = queue.pop_front() {
+            for (symbol, child) in &trie.children[node] {
+                let mut fallback = fail[node];
+                let child_fail = loop {
+                    match trie.children[fallback].get(symbol) {
+                        Some(next) => break *next,
+                        None if fallback == ROOT => break ROOT,
+                        None => fallback = fail[fallback],
+                    }
+                };
+                fail[*child] = child_fail;
+                let inherited = outputs[child_fail].clone();
+                outputs[*child].extend(inherited);
+                queue.push_back(*child);
+            }
+        }
+        AhoCorasick {
+            trie,
+            fail,
+            outputs,
+        }
+    }
+
+    pub fn trie(&self) -> &Trie<T> {
+        &self.trie
+    }
+
+    fn next_node(&self, mut node: usize, symbol: &T) -> usize {
+        loop {
+            if let Some(child) = self.trie.children[node].get(symbol) {
+                return *child;
+            }
+            if node == ROOT {
+                return ROOT;
+            }
+            node = self.fail[node];
+        }
+    }
+
+    /// Every match in `text`, overlapping ones included, ordered by where they end.
+    pub fn find_iter<'a>(
+        &'a self,
+        text: impl IntoIterator<Item = T> + 'a,
+    ) -> impl Iterator<Item = Match> + 'a {
+        let mut node = ROOT;
+        text.into_iter().enumerate().flat_map(move |(idx, symbol)| {
+            node = self.next_node(node, &symbol);
+            self.outputs[node].iter().map(move |pattern| Match {
+                pattern: *pattern,
+                start: idx + 1 - self.trie.pattern_len(*pattern),
+                end: idx + 1,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_trie {
+    use crate::trie::{AhoCorasick, Match, Trie};
+
+    fn trie(patterns: &[&str]) -> Trie<u8> {
+        let mut trie = Trie::new();
+        for pattern in patterns {
+            trie.insert(pattern.bytes());
+        }
+        trie
+    }
+
+    #[test]
+    fn test_matches_at() {
+        let mut trie = trie(&["b", "br", "bwu", "r", "brw"]);
+        assert_eq!(1, trie.insert("br".bytes()));
+        assert_eq!(5, trie.len());
+        assert!(trie.contains(b"bwu") && !trie.contains(b"bw"));
+
+        let text = b"brwrr";
+        let ends = trie
+            .matches_at(text, 0)
+            .map(|found| found.end)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 3], ends);
+        assert_eq!(
+            vec![Match {
+                pattern: 3,
+                start: 3,
+                end: 4
+            }],
+            trie.matches_at(text, 3).collect::<Vec<_>>()
+        );
+        assert_eq!(0, trie.matches_at(text, 2).count());
+    }
+
+    // Checked against looking for every pattern at every position
+    #[test]
+    fn test_find_iter_against_naive() {
+        let patterns = ["he", "she", "his", "hers", "e", "ss", "s"];
+        let automaton = AhoCorasick::new(trie(&patterns));
+        let text = "ushershisssheshe";
+        let mut found = automaton.find_iter(text.bytes()).collect::<Vec<_>>();
+        found.sort_by_key(|found| (found.start, found.pattern));
+
+        let mut expected = Vec::new();
+        for start in 0..text.len() {
+            for (pattern, word) in patterns.iter().enumerate() {
+                if text[start..].starts_with(word) {
+                    expected.push(Match {
+                        pattern,
+                        start,
+                        end: start + word.len(),
+                    });
+                }
+            }
+        }
+        expected.sort_by_key(|found| (found.start, found.pattern));
+        assert_eq!(expected, found);
+    }
+}
@@ -0,0 +1,192 @@
+/// Disjoint sets over `0..len`. Finds compress the path they walk and unions hang the smaller
+/// tree off the larger, which keeps every operation close to constant time.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    // Only meaningful for roots
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// Every element starts in a set of its own.
+    pub fn new(len: usize) -> Self {
+        UnionFind {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The root standing in for `element`'s set.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Point everything on the way straight at the root
+        let mut current = element;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        root
+    }
+
+    /// Joins the two sets, returning false if they were already the same one.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (big, small) = if self.size[a] >= self.size[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[small] = big;
+        self.size[big] += self.size[small];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// How many elements are in `element`'s set.
+    pub fn size_of(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// How many separate sets there are.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+}
+
+/// Connected components of a grid, each cell labelled with a number in `0..count()`. Labels are
+/// handed out in reading order of each component's first cell.
+#[derive(Debug, Clone)]
+pub struct GridLabels {
+    width: usize,
+    height: usize,
+    labels: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl GridLabels {
+    /// Joins each cell with its 4 neighbours wherever `same` says they belong together.
+    pub fn new<T>(grid: &[Vec<T>], same: impl Fn(&T, &T) -> bool) -> Self {
+        let height = grid.len();
+        let width = grid.first().map_or(0, |row| row.len());
+        let mut sets = UnionFind::new(width * height);
+        for (row, cells) in grid.iter().enumerate() {
+            for (col, cell) in cells.iter().enumerate() {
+                if col + 1 < width && same(cell, &cells[col + 1]) {
+                    sets.union(row * width + col, row * width + col + 1);
+                }
+                if row + 1 < height && same(cell, &grid[row + 1][col]) {
+                    sets.union(row * width + col, (row + 1) * width + col);
+                }
+            }
+        }
+
+        let mut label_of_root = vec![None; width * height];
+        let mut sizes = Vec::with_capacity(sets.components());
+        let labels = (0..width * height)
+            .map(|cell| {
+                let root = sets.find(cell);
+                *label_of_root[root].get_or_insert_with(|| {
+                    sizes.push(sets.size[root]);
+                    sizes.len() - 1
+                })
+            })
+            .collect();
+        GridLabels {
+            width,
+            height,
+            labels,
+            sizes,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn label(&self, row: usize, col: usize) -> usize {
+        assert!(col < self.width, "column {col} is outside the grid");
+        self.labels[row * self.width + col]
+    }
+
+    /// How many components there are.
+    pub fn count(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// How many cells have `label`.
+    pub fn size(&self, label: usize) -> usize {
+        self.sizes[label]
+    }
+}
+
+#[cfg(test)]
+mod test_union_find {
+    use crate::union_find::{GridLabels, UnionFind};
+
+    #[test]
+    fn test_union_and_sizes() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(6, sets.components());
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(4, sets.size_of(2));
+        assert_eq!(1, sets.size_of(5));
+        assert_eq!(3, sets.components());
+    }
+
+    #[test]
+    fn test_long_chain_compresses() {
+        let mut sets = UnionFind::new(100_000);
+        for element in 1..sets.len() {
+            sets.union(element - 1, element);
+        }
+        assert_eq!(1, sets.components());
+        assert_eq!(100_000, sets.size_of(99_999));
+    }
+
+    #[test]
+    fn test_grid_labels() {
+        let grid = ["AAB", "BAB", "BBB", "ACA"]
+            .iter()
+            .map(|row| row.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let labels = GridLabels::new(&grid, |a, b| a == b);
+        // The two Bs at the top only meet round the bottom, the As at the bottom don't touch
+        assert_eq!(5, labels.count());
+        assert_eq!((0, 1), (labels.label(0, 0), labels.label(0, 2)));
+        assert_eq!(labels.label(0, 2), labels.label(1, 0));
+        assert_ne!(labels.label(3, 0), labels.label(3, 2));
+        assert_eq!(
+            vec![3, 6, 1, 1, 1],
+            (0..5).map(|label| labels.size(label)).collect::<Vec<_>>()
+        );
+    }
+}